@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use bevy::{
+    ecs::schedule::{ExecutorKind, Schedule},
+    prelude::*,
+    tasks::{ComputeTaskPool, TaskPool},
+};
+use bevy_time_runner::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn secs(secs: f32) -> Duration {
+    Duration::from_secs_f32(secs)
+}
+
+fn tick_all(mut tick_time_runners: TickTimeRunners<()>) {
+    tick_time_runners.tick(0.01);
+}
+
+fn world() -> World {
+    let mut world = World::default();
+    world.init_resource::<Events<TimeRunnerStarted>>();
+    world.init_resource::<Events<TimeRunnerPaused>>();
+    world.init_resource::<Events<TimeRunnerResumed>>();
+    world.init_resource::<Events<TimeRunnerLooped>>();
+    world.init_resource::<Events<TimeRunnerEnded>>();
+    world.init_resource::<Events<TimeSpanEntered>>();
+    world.init_resource::<Events<TimeSpanExited>>();
+    world
+}
+
+fn spawn_runners(world: &mut World, runners: usize, spans: usize) {
+    for _ in 0..runners {
+        world
+            .spawn(
+                TimeRunner::new(secs(spans as f32))
+                    .with_repeat(Some((Repeat::Infinitely, RepeatStyle::WrapAround))),
+            )
+            .with_children(|c| {
+                for i in 0..spans {
+                    c.spawn(TimeSpan::try_from(secs(i as f32)..secs(i as f32 + 1.)).unwrap());
+                }
+            });
+    }
+}
+
+fn time_runner_system_bench(c: &mut Criterion) {
+    ComputeTaskPool::get_or_init(TaskPool::default);
+
+    let mut world = world();
+    spawn_runners(&mut world, 1000, 10);
+
+    let mut schedule = Schedule::default();
+    schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+    schedule.add_systems((tick_all, time_runner_system).chain());
+
+    c.bench_function("time_runner_system 1000 runners 10 spans", |b| {
+        b.iter(|| {
+            schedule.run(&mut world);
+            world.clear_trackers();
+        })
+    });
+}
+
+fn paused_time_runner_system_bench(c: &mut Criterion) {
+    let mut world = world();
+    spawn_runners(&mut world, 10000, 1);
+    world
+        .query::<&mut TimeRunner>()
+        .iter_mut(&mut world)
+        .for_each(|mut runner| {
+            runner.set_paused(true);
+        });
+
+    let mut schedule = Schedule::default();
+    schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+    schedule.add_systems((tick_all, time_runner_system).chain());
+    schedule.run(&mut world);
+
+    c.bench_function("time_runner_system 10000 paused runners", |b| {
+        b.iter(|| {
+            schedule.run(&mut world);
+            world.clear_trackers();
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    time_runner_system_bench,
+    paused_time_runner_system_bench
+);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+use bevy_ecs::{prelude::*, system::EntityCommands};
+use bevy_hierarchy::prelude::*;
+
+use crate::{time_runner::*, time_span::*};
+
+/// Extension trait for spawning a [`TimeRunner`] with its spans from [`Commands`]
+pub trait TimeRunnerCommandsExt {
+    /// Spawn `runner` with each span and its bundle as a child
+    fn spawn_time_runner<B: Bundle>(
+        &mut self,
+        runner: TimeRunner,
+        spans: Vec<(TimeSpan, B)>,
+    ) -> EntityCommands<'_>;
+}
+
+impl TimeRunnerCommandsExt for Commands<'_, '_> {
+    fn spawn_time_runner<B: Bundle>(
+        &mut self,
+        runner: TimeRunner,
+        spans: Vec<(TimeSpan, B)>,
+    ) -> EntityCommands<'_> {
+        let mut entity = self.spawn(runner);
+        entity.with_children(|c| {
+            for span in spans {
+                c.spawn(span);
+            }
+        });
+        entity
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use bevy_ecs::world::CommandQueue;
+
+    use super::*;
+
+    #[test]
+    fn spawn_time_runner() {
+        fn secs(secs: u64) -> Duration {
+            Duration::from_secs(secs)
+        }
+
+        let mut world = World::default();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let runner = commands
+            .spawn_time_runner(
+                TimeRunner::new(secs(2)),
+                vec![
+                    (
+                        TimeSpan::try_from(secs(0)..secs(1)).unwrap(),
+                        TimeSpanLabel::from("a"),
+                    ),
+                    (
+                        TimeSpan::try_from(secs(1)..secs(2)).unwrap(),
+                        TimeSpanLabel::from("b"),
+                    ),
+                ],
+            )
+            .id();
+        queue.apply(&mut world);
+
+        let children = world.get::<Children>(runner).unwrap();
+        assert_eq!(children.len(), 2);
+        assert_eq!(
+            world.get::<TimeSpanLabel>(children[1]),
+            Some(&TimeSpanLabel::from("b"))
+        );
+        assert_eq!(
+            world.get::<TimeSpan>(children[1]),
+            Some(&TimeSpan::try_from(secs(1)..secs(2)).unwrap())
+        );
+    }
+}
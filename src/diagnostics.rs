@@ -0,0 +1,128 @@
+use bevy_app::prelude::*;
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+    system::SystemState,
+};
+
+use crate::{time_runner::*, time_source::*, time_span::*, TimeRunnerSet};
+
+/// Adds diagnostics of runners and spans, measured in [`TimeRunnerSet::PostProgress`].
+///
+/// [`TimeRunnerPlugin`](crate::TimeRunnerPlugin) is required.
+#[derive(Debug)]
+pub struct TimeRunnerDiagnosticsPlugin {
+    /// [`TimeRunnerPlugin::progress_schedule`](crate::TimeRunnerPlugin::progress_schedule)
+    pub schedule: InternedScheduleLabel,
+}
+
+impl Default for TimeRunnerDiagnosticsPlugin {
+    fn default() -> Self {
+        TimeRunnerDiagnosticsPlugin {
+            schedule: PostUpdate.intern(),
+        }
+    }
+}
+
+impl TimeRunnerDiagnosticsPlugin {
+    /// Number of [`TimeRunner`] that are not paused nor completed
+    pub const ACTIVE_RUNNERS: DiagnosticPath =
+        DiagnosticPath::const_new("bevy_time_runner/active_runners");
+    /// Number of [`TimeSpanProgress`] updated this frame
+    pub const SPANS_UPDATED: DiagnosticPath =
+        DiagnosticPath::const_new("bevy_time_runner/spans_updated");
+    /// Delta seconds from the [`TimeSource`] used for ticking runners
+    pub const TICK_DELTA: DiagnosticPath = DiagnosticPath::const_new("bevy_time_runner/tick_delta");
+}
+
+impl Plugin for TimeRunnerDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::ACTIVE_RUNNERS))
+            .register_diagnostic(Diagnostic::new(Self::SPANS_UPDATED))
+            .register_diagnostic(Diagnostic::new(Self::TICK_DELTA).with_suffix("s"))
+            .add_systems(
+                self.schedule,
+                time_runner_diagnostics_system.in_set(TimeRunnerSet::PostProgress),
+            );
+    }
+}
+
+type DiagnosticsState<'w, 's> = (
+    Diagnostics<'w, 's>,
+    Query<'w, 's, &'static TimeRunner>,
+    Query<'w, 's, (), Changed<TimeSpanProgress>>,
+);
+
+/// System for measuring every diagnostic in [`TimeRunnerDiagnosticsPlugin`]
+pub fn time_runner_diagnostics_system(
+    world: &mut World,
+    state: &mut SystemState<DiagnosticsState<'static, 'static>>,
+) {
+    let delta = time_source_delta(world);
+    let (mut diagnostics, q_runner, q_span_updated) = state.get_mut(world);
+    diagnostics.add_measurement(&TimeRunnerDiagnosticsPlugin::ACTIVE_RUNNERS, || {
+        q_runner
+            .iter()
+            .filter(|runner| !runner.paused() && !runner.is_completed())
+            .count() as f64
+    });
+    diagnostics.add_measurement(&TimeRunnerDiagnosticsPlugin::SPANS_UPDATED, || {
+        q_span_updated.iter().count() as f64
+    });
+    diagnostics.add_measurement(&TimeRunnerDiagnosticsPlugin::TICK_DELTA, || delta as f64);
+    state.apply(world);
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use bevy::{
+        diagnostic::DiagnosticsStore,
+        hierarchy::{BuildChildren, ChildBuild},
+        time::{TimePlugin, TimeUpdateStrategy},
+    };
+
+    use super::*;
+    use crate::TimeRunnerPlugin;
+
+    fn secs(secs: f32) -> Duration {
+        Duration::from_secs_f32(secs)
+    }
+
+    #[test]
+    fn diagnostics() {
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            TimeRunnerPlugin::default(),
+            TimeRunnerDiagnosticsPlugin::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(secs(0.125)));
+        app.update();
+
+        app.world_mut()
+            .spawn(TimeRunner::new(secs(10.)))
+            .with_children(|c| {
+                c.spawn(TimeSpan::try_from(secs(0.)..secs(5.)).unwrap());
+                c.spawn(TimeSpan::try_from(secs(5.)..secs(10.)).unwrap());
+            });
+        app.world_mut()
+            .spawn(TimeRunner::new(secs(10.)).with_paused(true));
+        app.update();
+
+        let value = |path: &DiagnosticPath| {
+            app.world()
+                .resource::<DiagnosticsStore>()
+                .get(path)
+                .and_then(Diagnostic::value)
+        };
+        assert_eq!(
+            value(&TimeRunnerDiagnosticsPlugin::ACTIVE_RUNNERS),
+            Some(1.)
+        );
+        assert_eq!(value(&TimeRunnerDiagnosticsPlugin::SPANS_UPDATED), Some(1.));
+        assert_eq!(value(&TimeRunnerDiagnosticsPlugin::TICK_DELTA), Some(0.125));
+    }
+}
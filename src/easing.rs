@@ -0,0 +1,130 @@
+use bevy_ecs::prelude::*;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::prelude::*;
+
+use crate::time_span::*;
+
+/// Easing function for remapping percentage
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[allow(missing_docs)]
+pub enum EasingFn {
+    #[default]
+    Linear,
+    EaseInQuad,
+    EaseOutQuad,
+    EaseInOutQuad,
+    EaseInCubic,
+    EaseOutCubic,
+    EaseInOutCubic,
+}
+
+impl EasingFn {
+    /// Remap `t` with this easing function.
+    /// `t` outside of 0–1 is extrapolated with the same formula.
+    pub fn sample(&self, t: f32) -> f32 {
+        match self {
+            EasingFn::Linear => t,
+            EasingFn::EaseInQuad => t * t,
+            EasingFn::EaseOutQuad => 1. - (1. - t) * (1. - t),
+            EasingFn::EaseInOutQuad => {
+                if t < 0.5 {
+                    2. * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(2) / 2.
+                }
+            }
+            EasingFn::EaseInCubic => t * t * t,
+            EasingFn::EaseOutCubic => 1. - (1. - t).powi(3),
+            EasingFn::EaseInOutCubic => {
+                if t < 0.5 {
+                    4. * t * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(3) / 2.
+                }
+            }
+        }
+    }
+}
+
+/// Remaps [`TimeSpanProgress::now_percentage`] of this [`TimeSpan`] through
+/// an [`EasingFn`].
+///
+/// `now` stays as it is in seconds.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct EasedTimeSpan(pub EasingFn);
+
+/// System for applying [`EasedTimeSpan`] to freshly updated [`TimeSpanProgress`]
+pub fn eased_time_span_system(
+    mut q_span: Query<(&EasedTimeSpan, &mut TimeSpanProgress), Changed<TimeSpanProgress>>,
+) {
+    q_span.iter_mut().for_each(|(eased, mut progress)| {
+        // previous_percentage is already eased from the last update
+        // except when the progress is just inserted.
+        if progress.is_added() {
+            progress.previous_percentage = eased.0.sample(progress.previous_percentage);
+        }
+        progress.now_percentage = eased.0.sample(progress.now_percentage);
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn easing_fn_endpoints() {
+        for easing in [
+            EasingFn::Linear,
+            EasingFn::EaseInQuad,
+            EasingFn::EaseOutQuad,
+            EasingFn::EaseInOutQuad,
+            EasingFn::EaseInCubic,
+            EasingFn::EaseOutCubic,
+            EasingFn::EaseInOutCubic,
+        ] {
+            assert_eq!(easing.sample(0.), 0., "{easing:?}");
+            assert_eq!(easing.sample(1.), 1., "{easing:?}");
+        }
+        assert_eq!(EasingFn::EaseInQuad.sample(0.5), 0.25);
+        assert_eq!(EasingFn::EaseOutQuad.sample(0.5), 0.75);
+        assert_eq!(EasingFn::EaseInOutCubic.sample(0.5), 0.5);
+    }
+
+    #[test]
+    fn eased_time_span() {
+        let mut world = World::default();
+        let system = world.register_system(eased_time_span_system);
+        let span = world
+            .spawn((
+                EasedTimeSpan(EasingFn::EaseInQuad),
+                TimeSpanProgress {
+                    now_percentage: 0.5,
+                    now: 1.,
+                    previous_percentage: 0.5,
+                    previous: 1.,
+                },
+            ))
+            .id();
+        let progress = |world: &World| {
+            let progress = world.get::<TimeSpanProgress>(span).unwrap();
+            (progress.previous_percentage, progress.now_percentage)
+        };
+
+        world.run_system(system).unwrap();
+        assert_eq!(progress(&world), (0.25, 0.25));
+
+        // not updated by any runner so it should not be eased twice
+        world.run_system(system).unwrap();
+        assert_eq!(progress(&world), (0.25, 0.25));
+
+        world
+            .get_mut::<TimeSpanProgress>(span)
+            .unwrap()
+            .update(2., 1.);
+        world.run_system(system).unwrap();
+        assert_eq!(progress(&world), (0.25, 1.));
+    }
+}
@@ -41,26 +41,117 @@ use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 #[cfg(feature = "bevy_app")]
 use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+#[cfg(feature = "bevy_app")]
+use std::sync::{Mutex, PoisonError};
 
+mod commands;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod easing;
+mod scrub;
+#[cfg(feature = "serde")]
+mod serde_duration;
+#[cfg(feature = "test_helpers")]
+mod test_helpers;
 mod time_runner;
+mod time_source;
 mod time_span;
+mod time_span_group;
+#[cfg(feature = "asset")]
+mod timeline_asset;
+#[cfg(feature = "validate_spans")]
+mod validation;
+pub use commands::*;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::*;
+pub use easing::*;
+pub use scrub::*;
+#[cfg(feature = "test_helpers")]
+pub use test_helpers::*;
 pub use time_runner::*;
+pub use time_source::*;
 pub use time_span::*;
+pub use time_span_group::*;
+#[cfg(feature = "asset")]
+pub use timeline_asset::*;
+#[cfg(feature = "validate_spans")]
+pub use validation::*;
 
 /// Add [`time_runner_system`]
 /// Registers [`TimeRunner`]
 #[cfg(feature = "bevy_app")]
 #[derive(Debug)]
 pub struct TimeRunnerPlugin {
-    /// All systems will be put to this schedule
-    pub schedule: InternedScheduleLabel,
+    /// Schedule for systems in [`TimeRunnerSet::PreTick`] and [`TimeRunnerSet::TickTimer`]
+    pub tick_schedule: InternedScheduleLabel,
+    /// Schedule for systems in [`TimeRunnerSet::Progress`], [`TimeRunnerSet::GroupProgress`]
+    /// and [`TimeRunnerSet::PostProgress`].
+    ///
+    /// Should run after `tick_schedule` in the same frame, e.g. ticking in
+    /// [`FixedUpdate`] then updating progress in [`PostUpdate`].
+    pub progress_schedule: InternedScheduleLabel,
+    /// [`TimeSource`] to be inserted as a resource on build, set with
+    /// [`TimeRunnerPlugin::with_time_source`].
+    /// If `None`, [`VirtualTimeSource`] is used unless there's already one.
+    time_source: Mutex<Option<Box<dyn TimeSource>>>,
+    /// Insert [`GlobalTimeScale`] on build if there's none.
+    pub global_time_scale: bool,
+}
+
+#[cfg(feature = "bevy_app")]
+impl TimeRunnerPlugin {
+    /// Tick all runners using this [`TimeSource`]
+    pub fn with_time_source(self, time_source: Box<dyn TimeSource>) -> Self {
+        TimeRunnerPlugin {
+            time_source: Mutex::new(Some(time_source)),
+            ..self
+        }
+    }
+
+    /// Tick all runners using [`Time<Virtual>`](bevy_time::Virtual).
+    ///
+    /// This is already the default unless a [`TimeSource`] resource is inserted
+    /// before this plugin is built.
+    pub fn with_virtual_time(self) -> Self {
+        self.with_time_source(Box::new(VirtualTimeSource))
+    }
+
+    /// Put all systems to this schedule
+    pub fn with_schedule(self, schedule: impl ScheduleLabel) -> Self {
+        let schedule = schedule.intern();
+        self.with_schedules(schedule, schedule)
+    }
+
+    /// Tick runners in `tick_schedule` and update their spans in `progress_schedule`
+    pub fn with_schedules(
+        self,
+        tick_schedule: impl ScheduleLabel,
+        progress_schedule: impl ScheduleLabel,
+    ) -> Self {
+        TimeRunnerPlugin {
+            tick_schedule: tick_schedule.intern(),
+            progress_schedule: progress_schedule.intern(),
+            ..self
+        }
+    }
+
+    /// Insert [`GlobalTimeScale`] of `1.0` on build
+    pub fn with_global_time_scale(self) -> Self {
+        TimeRunnerPlugin {
+            global_time_scale: true,
+            ..self
+        }
+    }
 }
 
 #[cfg(feature = "bevy_app")]
 impl Default for TimeRunnerPlugin {
     fn default() -> Self {
         TimeRunnerPlugin {
-            schedule: PostUpdate.intern(),
+            tick_schedule: PostUpdate.intern(),
+            progress_schedule: PostUpdate.intern(),
+            time_source: Mutex::new(None),
+            global_time_scale: false,
         }
     }
 }
@@ -68,38 +159,519 @@ impl Default for TimeRunnerPlugin {
 #[cfg(feature = "bevy_app")]
 impl Plugin for TimeRunnerPlugin {
     fn build(&self, app: &mut App) {
-        app.configure_sets(
-            self.schedule,
-            (TimeRunnerSet::TickTimer, TimeRunnerSet::Progress).chain(),
+        if self.tick_schedule == self.progress_schedule {
+            app.configure_sets(
+                self.tick_schedule,
+                (
+                    TimeRunnerSet::PreTick,
+                    TimeRunnerSet::TickTimer,
+                    TimeRunnerSet::Progress,
+                    TimeRunnerSet::GroupProgress,
+                    TimeRunnerSet::PostProgress,
+                )
+                    .chain(),
+            );
+        } else {
+            if let (Some(tick), Some(progress)) = (
+                main_schedule_order(self.tick_schedule),
+                main_schedule_order(self.progress_schedule),
+            ) {
+                if tick > progress {
+                    bevy_utils::tracing::warn!(
+                        "TimeRunnerPlugin tick schedule {:?} runs after progress schedule {:?}",
+                        self.tick_schedule,
+                        self.progress_schedule
+                    );
+                }
+            }
+            app.configure_sets(
+                self.tick_schedule,
+                (TimeRunnerSet::PreTick, TimeRunnerSet::TickTimer).chain(),
+            )
+            .configure_sets(
+                self.progress_schedule,
+                (
+                    TimeRunnerSet::Progress,
+                    TimeRunnerSet::GroupProgress,
+                    TimeRunnerSet::PostProgress,
+                )
+                    .chain(),
+            );
+        }
+
+        app.add_systems(
+            self.tick_schedule,
+            (
+                time_scrubber_system.in_set(TimeRunnerSet::PreTick),
+                smooth_seek_system.in_set(TimeRunnerSet::PreTick),
+                tick_time_runner_system.in_set(TimeRunnerSet::TickTimer),
+                synced_time_runners_system
+                    .after(TimeRunnerSet::TickTimer)
+                    .before(TimeRunnerSet::Progress),
+            ),
         )
         .add_systems(
-            self.schedule,
+            self.progress_schedule,
             (
-                tick_time_runner_system.in_set(TimeRunnerSet::TickTimer),
+                time_runner_cleanup_system
+                    .in_set(TimeRunnerSet::Progress)
+                    .before(time_runner_ref_system),
+                time_runner_ref_system
+                    .in_set(TimeRunnerSet::Progress)
+                    .before(time_runner_system),
                 time_runner_system.in_set(TimeRunnerSet::Progress),
+                time_span_group_system.in_set(TimeRunnerSet::GroupProgress),
+                eased_time_span_system.in_set(TimeRunnerSet::PostProgress),
+                scheduled_pause_system
+                    .in_set(TimeRunnerSet::PostProgress)
+                    .before(time_runner_state_system),
+                time_runner_state_system.in_set(TimeRunnerSet::PostProgress),
+                time_until_complete_system.in_set(TimeRunnerSet::PostProgress),
+                time_runner_progress_system.in_set(TimeRunnerSet::PostProgress),
+                repeat_progress_system.in_set(TimeRunnerSet::PostProgress),
             ),
         )
-        .add_event::<TimeRunnerEnded>();
+        .add_event::<TimeRunnerStarted>()
+        .add_event::<TimeRunnerPaused>()
+        .add_event::<TimeRunnerResumed>()
+        .add_event::<TimeRunnerLooped>()
+        .add_event::<TimeRunnerEnded>()
+        .add_event::<TimeSpanEntered>()
+        .add_event::<TimeSpanExited>();
+
+        let time_source = self
+            .time_source
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        match time_source {
+            Some(time_source) => {
+                app.insert_resource(time_source);
+            }
+            None => {
+                if !app.world().contains_resource::<Box<dyn TimeSource>>() {
+                    app.insert_resource::<Box<dyn TimeSource>>(Box::new(VirtualTimeSource));
+                }
+            }
+        }
+
+        #[cfg(feature = "validate_spans")]
+        app.add_event::<TimeSpanValidationWarning>().add_systems(
+            self.tick_schedule,
+            time_span_validation_system.in_set(TimeRunnerSet::PreTick),
+        );
+
+        if self.global_time_scale {
+            app.init_resource::<GlobalTimeScale>();
+        }
 
         #[cfg(feature = "bevy_reflect")]
         app.register_type::<TimeRunner>()
             .register_type::<SkipTimeRunner>()
+            .register_type::<FixedTickRunner>()
+            .register_type::<AutoDespawnOnComplete>()
+            .register_type::<TotalElapsed>()
+            .register_type::<ScheduledPause>()
+            .register_type::<TimeUntilComplete>()
+            .register_type::<TimeRunnerProgress>()
+            .register_type::<RepeatProgress>()
+            .register_type::<SyncedTimeRunners>()
+            .register_type::<TimeScrubber>()
+            .register_type::<SmoothSeek>()
+            .register_type::<GlobalTimeScale>()
+            .register_type::<IgnoreGlobalTimeScale>()
+            .register_type::<GlobalTimePause>()
+            .register_type::<IgnoreGlobalTimePause>()
+            .register_type::<StartDelay>()
+            .register_type::<RepeatDelay>()
             .register_type::<TimeRunnerElasped>()
+            .register_type::<TimeRunnerElaspedF64>()
+            .register_type::<TimeRunnerSnapshot>()
+            .register_type::<TimeRunnerState>()
+            .register_type::<TimeRunnerStarted>()
+            .register_type::<TimeRunnerPaused>()
+            .register_type::<TimeRunnerResumed>()
+            .register_type::<TimeRunnerLooped>()
             .register_type::<TimeRunnerEnded>()
+            .register_type::<OnTimeRunnerCompleted>()
             .register_type::<TimeSpan>()
             .register_type::<TimeSpanProgress>()
+            .register_type::<TimeSpanState>()
+            .register_type::<TimeSpanGroup>()
+            .register_type::<SkipTimeSpan>()
+            .register_type::<TimeSpanLabel>()
+            .register_type::<TimeSpanRef>()
+            .register_type::<TimeSpanEntered>()
+            .register_type::<TimeSpanExited>()
+            .register_type::<EasedTimeSpan>()
+            .register_type::<EasingFn>()
             .register_type::<Repeat>()
             .register_type::<RepeatStyle>()
+            .register_type::<WrapOffset>()
             .register_type::<TimeBound>()
             .register_type::<TimeDirection>();
+        #[cfg(all(feature = "bevy_reflect", feature = "validate_spans"))]
+        app.register_type::<TimeSpanValidationWarning>()
+            .register_type::<TimeSpanValidationKind>();
     }
 }
 
-/// System set in this crate
+/// Position of `schedule` within [`Main`] or `None` if it's not a main schedule.
+#[cfg(feature = "bevy_app")]
+fn main_schedule_order(schedule: InternedScheduleLabel) -> Option<usize> {
+    [
+        First.intern(),
+        PreUpdate.intern(),
+        RunFixedMainLoop.intern(),
+        FixedFirst.intern(),
+        FixedPreUpdate.intern(),
+        FixedUpdate.intern(),
+        FixedPostUpdate.intern(),
+        FixedLast.intern(),
+        Update.intern(),
+        PostUpdate.intern(),
+        Last.intern(),
+    ]
+    .iter()
+    .position(|s| *s == schedule)
+}
+
+/// Add [`fixed_tick_time_runner_system`] to [`FixedUpdate`] for ticking any
+/// [`TimeRunner`] with [`FixedTickRunner`].
+///
+/// This plugin only handles ticking. [`TimeRunnerPlugin`] is still required
+/// for updating [`TimeSpanProgress`] and registering types.
+#[cfg(feature = "bevy_app")]
+#[derive(Debug, Default)]
+pub struct FixedUpdateTimeRunnerPlugin;
+
+#[cfg(feature = "bevy_app")]
+impl Plugin for FixedUpdateTimeRunnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            fixed_tick_time_runner_system.in_set(TimeRunnerSet::TickTimer),
+        );
+    }
+}
+
+/// System set in this crate.
+///
+/// Configured by [`TimeRunnerPlugin`] to run in the order of
+/// `PreTick`, `TickTimer`, `Progress`, `GroupProgress` then `PostProgress`.
+/// `PreTick` and `TickTimer` are in [`TimeRunnerPlugin::tick_schedule`] while
+/// the rest are in [`TimeRunnerPlugin::progress_schedule`].
 #[derive(Debug, PartialEq, Eq, Hash, Clone, SystemSet)]
 pub enum TimeRunnerSet {
+    /// Systems that should run right before any runner is ticked,
+    /// e.g. modifying [`TimeRunner`] for this frame like [`time_scrubber_system`].
+    PreTick,
     /// Systems responsible for ticking timer
     TickTimer,
     /// Systems responsible for updating [`TimeSpanProgress`]
     Progress,
+    /// Systems responsible for updating [`TimeSpanProgress`] of spans in [`TimeSpanGroup`]
+    GroupProgress,
+    /// Systems responsible for post-processing [`TimeSpanProgress`] such as [`EasedTimeSpan`].
+    /// User systems reading the final [`TimeSpanProgress`] of this frame can go here.
+    PostProgress,
+}
+
+#[cfg(all(test, feature = "bevy_app"))]
+mod test {
+    use std::time::Duration;
+
+    use bevy::hierarchy::{BuildChildren, ChildBuild};
+    use bevy::time::{Fixed, Time, TimePlugin, TimeUpdateStrategy, Virtual};
+
+    use super::*;
+
+    fn secs(secs: f32) -> Duration {
+        Duration::from_secs_f32(secs)
+    }
+
+    fn app(time_source: impl TimeSource) -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            TimeRunnerPlugin::default().with_time_source(Box::new(time_source)),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(secs(0.125)));
+        // First update only initialize the time
+        app.update();
+        app
+    }
+
+    fn elasped_now(app: &App, runner: Entity) -> f32 {
+        app.world()
+            .get::<TimeRunner>(runner)
+            .unwrap()
+            .elasped()
+            .now()
+    }
+
+    #[test]
+    fn virtual_time_pause_stops_runners() {
+        let mut app = app(VirtualTimeSource);
+        let runner = app.world_mut().spawn(TimeRunner::new(secs(10.))).id();
+
+        app.update();
+        assert_eq!(elasped_now(&app, runner), 0.125);
+
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.update();
+        app.update();
+        assert_eq!(elasped_now(&app, runner), 0.125);
+    }
+
+    #[test]
+    fn fixed_tick_runner_only_ticks_in_fixed_update() {
+        let mut app = app(VirtualTimeSource);
+        app.add_plugins(FixedUpdateTimeRunnerPlugin)
+            .insert_resource(Time::<Fixed>::from_seconds(0.25));
+        let runner = app.world_mut().spawn(TimeRunner::new(secs(10.))).id();
+        let fixed_runner = app
+            .world_mut()
+            .spawn((TimeRunner::new(secs(10.)), FixedTickRunner))
+            .id();
+
+        app.update();
+        assert_eq!(elasped_now(&app, runner), 0.125);
+        assert_eq!(elasped_now(&app, fixed_runner), 0.);
+
+        app.update();
+        assert_eq!(elasped_now(&app, runner), 0.25);
+        assert_eq!(elasped_now(&app, fixed_runner), 0.25);
+    }
+
+    #[test]
+    fn separate_tick_and_progress_schedules() {
+        #[derive(Resource, Default)]
+        struct ElaspedInUpdate(f32);
+
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            TimeRunnerPlugin::default()
+                .with_virtual_time()
+                .with_schedules(PreUpdate, PostUpdate),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(secs(0.125)))
+        .init_resource::<ElaspedInUpdate>()
+        .add_systems(
+            Update,
+            |q_runner: Query<&TimeRunner>,
+             q_progress: Query<(), With<TimeSpanProgress>>,
+             mut elasped: ResMut<ElaspedInUpdate>| {
+                if let Ok(runner) = q_runner.get_single() {
+                    // spans are not updated yet
+                    assert!(q_progress.is_empty());
+                    elasped.0 = runner.elasped().now();
+                }
+            },
+        );
+        app.update();
+        app.world_mut()
+            .spawn(TimeRunner::new(secs(10.)))
+            .with_children(|c| {
+                c.spawn(TimeSpan::try_from(secs(0.)..secs(1.)).unwrap());
+            });
+
+        app.update();
+        assert_eq!(app.world().resource::<ElaspedInUpdate>().0, 0.125);
+        let mut q_progress = app.world_mut().query::<&TimeSpanProgress>();
+        assert_eq!(q_progress.single(app.world()).now, 0.125);
+    }
+
+    fn event_count<E: Event>(app: &App) -> usize {
+        app.world()
+            .resource::<Events<E>>()
+            .iter_current_update_events()
+            .count()
+    }
+
+    #[test]
+    fn time_runner_started() {
+        let started_count = event_count::<TimeRunnerStarted>;
+
+        let mut app = app(VirtualTimeSource);
+        let runner = app
+            .world_mut()
+            .spawn(TimeRunner::new(secs(0.25)).with_paused(true))
+            .id();
+
+        app.update();
+        assert_eq!(started_count(&app), 0);
+
+        app.world_mut()
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(false);
+        app.update();
+        assert_eq!(started_count(&app), 1);
+
+        app.update();
+        assert_eq!(started_count(&app), 0);
+
+        // completed
+        app.update();
+        app.world_mut()
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .reset();
+        app.update();
+        assert_eq!(started_count(&app), 1);
+    }
+
+    #[test]
+    fn time_runner_paused_resumed() {
+        let mut app = app(VirtualTimeSource);
+        let runner = app.world_mut().spawn(TimeRunner::new(secs(10.))).id();
+
+        app.update();
+        assert_eq!(event_count::<TimeRunnerPaused>(&app), 0);
+        assert_eq!(event_count::<TimeRunnerResumed>(&app), 0);
+
+        app.world_mut()
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(true);
+        app.update();
+        assert_eq!(event_count::<TimeRunnerPaused>(&app), 1);
+        assert_eq!(event_count::<TimeRunnerResumed>(&app), 0);
+
+        app.update();
+        assert_eq!(event_count::<TimeRunnerPaused>(&app), 0);
+
+        app.world_mut()
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(false);
+        app.update();
+        assert_eq!(event_count::<TimeRunnerPaused>(&app), 0);
+        assert_eq!(event_count::<TimeRunnerResumed>(&app), 1);
+    }
+
+    #[test]
+    fn time_runner_looped_ended() {
+        fn loop_indices(app: &App) -> Vec<u32> {
+            app.world()
+                .resource::<Events<TimeRunnerLooped>>()
+                .iter_current_update_events()
+                .map(|event| event.loop_index)
+                .collect()
+        }
+        let ended_count = event_count::<TimeRunnerEnded>;
+
+        let mut app = app(VirtualTimeSource);
+        app.world_mut().spawn(
+            TimeRunner::new(secs(0.25))
+                .with_repeat(Some((Repeat::times(2), RepeatStyle::PingPong))),
+        );
+
+        let mut frames = vec![];
+        let mut ended_loop_index = None;
+        for _ in 0..8 {
+            app.update();
+            frames.push((loop_indices(&app), ended_count(&app)));
+            if let Some(event) = app
+                .world()
+                .resource::<Events<TimeRunnerEnded>>()
+                .iter_current_update_events()
+                .next()
+            {
+                ended_loop_index = Some(event.loop_index);
+            }
+        }
+        assert_eq!(ended_loop_index, Some(2));
+        assert_eq!(
+            frames,
+            [
+                (vec![], 0),
+                (vec![0], 0),
+                (vec![], 0),
+                (vec![], 0),
+                (vec![1], 0),
+                (vec![], 1),
+                (vec![], 0),
+                (vec![], 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn auto_despawn_on_complete() {
+        let mut app = app(VirtualTimeSource);
+        let mut span = Entity::PLACEHOLDER;
+        let runner = app
+            .world_mut()
+            .spawn((TimeRunner::new(secs(0.25)), AutoDespawnOnComplete))
+            .with_children(|c| {
+                span = c
+                    .spawn(TimeSpan::try_from(secs(0.)..=secs(0.25)).unwrap())
+                    .id();
+            })
+            .id();
+
+        app.update();
+        app.update();
+        assert!(app
+            .world()
+            .get::<TimeRunner>(runner)
+            .unwrap()
+            .is_completed());
+        assert_eq!(
+            app.world()
+                .get::<TimeSpanProgress>(span)
+                .unwrap()
+                .now_percentage,
+            1.
+        );
+
+        app.update();
+        assert!(app.world().get_entity(runner).is_err());
+        assert!(app.world().get_entity(span).is_err());
+    }
+
+    #[test]
+    fn with_virtual_time_overrides_time_source() {
+        let mut app = App::new();
+        app.insert_resource::<Box<dyn TimeSource>>(Box::new(RealTimeSource))
+            .add_plugins((TimePlugin, TimeRunnerPlugin::default().with_virtual_time()))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(secs(0.125)));
+        app.update();
+        let runner = app.world_mut().spawn(TimeRunner::new(secs(10.))).id();
+
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.update();
+        assert_eq!(elasped_now(&app, runner), 0.);
+    }
+
+    #[test]
+    fn real_time_ignores_virtual_time_pause() {
+        let mut app = app(RealTimeSource);
+        let runner = app.world_mut().spawn(TimeRunner::new(secs(10.))).id();
+
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.update();
+        app.update();
+        assert_eq!(elasped_now(&app, runner), 0.25);
+    }
+
+    #[test]
+    fn custom_time_source() {
+        struct ConstantTimeSource(f32);
+        impl TimeSource for ConstantTimeSource {
+            fn delta_secs(&self, _world: &World) -> f32 {
+                self.0
+            }
+        }
+
+        let mut app = app(ConstantTimeSource(0.5));
+        let runner = app.world_mut().spawn(TimeRunner::new(secs(10.))).id();
+
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.update();
+        assert_eq!(elasped_now(&app, runner), 0.5);
+    }
 }
@@ -0,0 +1,166 @@
+use bevy_ecs::{entity::EntityHashMap, prelude::*, system::SystemState};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::prelude::*;
+
+use crate::{time_runner::*, time_source::*};
+
+/// Drive the elasped percentage of `runner` with `scrub`, e.g. from a progress bar.
+///
+/// The runner is only seeked on frames where `scrub` has changed so it can
+/// still play normally in between. Remove this component to stop scrubbing.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct TimeScrubber {
+    /// [`TimeRunner`] to scrub
+    pub runner: Entity,
+    /// Elasped percentage to seek to, clamped within 0–1
+    pub scrub: f32,
+}
+
+/// System for seeking runners of [`TimeScrubber`] with [`TimeRunner::seek_normalized`]
+pub fn time_scrubber_system(
+    q_scrubber: Query<(Entity, &TimeScrubber)>,
+    mut q_runner: Query<&mut TimeRunner>,
+    mut last_scrubs: Local<EntityHashMap<f32>>,
+) {
+    last_scrubs.retain(|entity, _| q_scrubber.contains(*entity));
+    for (entity, scrubber) in &q_scrubber {
+        let scrub = scrubber.scrub.clamp(0., 1.);
+        if let Some(last_scrub) = last_scrubs.insert(entity, scrub) {
+            if (scrub - last_scrub).abs() <= f32::EPSILON {
+                continue;
+            }
+        }
+        if let Ok(mut runner) = q_runner.get_mut(scrubber.runner) {
+            runner.seek_normalized(scrub);
+        }
+    }
+}
+
+/// Move the elasped time of this [`TimeRunner`] toward `target_secs` at
+/// `speed_secs_per_sec` instead of seeking instantly.
+///
+/// The runner isn't ticked while seeking so it doesn't advance on its own.
+/// Removes itself when within `epsilon` of the target.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct SmoothSeek {
+    /// Elasped seconds to seek to, clamped within the runner length
+    pub target_secs: f32,
+    /// Seeking speed
+    pub speed_secs_per_sec: f32,
+    /// Distance from the target to be considered arrived
+    pub epsilon: f32,
+}
+
+impl SmoothSeek {
+    /// Create a new [`SmoothSeek`] with an `epsilon` of `0.001`
+    pub fn new(target_secs: f32, speed_secs_per_sec: f32) -> SmoothSeek {
+        SmoothSeek {
+            target_secs,
+            speed_secs_per_sec,
+            epsilon: 0.001,
+        }
+    }
+}
+
+/// System for moving runners with [`SmoothSeek`] toward their target using the
+/// [`TimeSource`] resource.
+///
+/// Uses [`VirtualTimeSource`] if there's no [`TimeSource`] resource.
+#[allow(clippy::type_complexity)]
+pub fn smooth_seek_system(
+    world: &mut World,
+    state: &mut SystemState<(Commands, Query<(Entity, &SmoothSeek, &mut TimeRunner)>)>,
+) {
+    let delta = time_source_delta(world);
+    let (mut commands, mut q_seek) = state.get_mut(world);
+
+    for (entity, seek, mut runner) in &mut q_seek {
+        let now = runner.elasped().now();
+        let target = seek.target_secs.clamp(0., runner.length().as_secs_f32());
+        let step = seek.speed_secs_per_sec * delta;
+        let distance = target - now;
+        if distance.abs() <= step.max(seek.epsilon) {
+            runner.set_tick(target);
+            commands.entity(entity).remove::<SmoothSeek>();
+        } else {
+            runner.set_tick(now + step.copysign(distance));
+        }
+    }
+    state.apply(world);
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::time_runner::test::run_systems;
+
+    #[test]
+    fn time_scrubber() {
+        let mut world = World::default();
+        let runner = world.spawn(TimeRunner::new(Duration::from_secs(4))).id();
+        let scrubber = world.spawn(TimeScrubber { runner, scrub: 0.5 }).id();
+        let system = world.register_system(time_scrubber_system);
+        let now = |world: &World| world.get::<TimeRunner>(runner).unwrap().elasped().now();
+
+        world.run_system(system).unwrap();
+        assert_eq!(now(&world), 2.);
+
+        // unchanged scrub lets the runner play
+        world.get_mut::<TimeRunner>(runner).unwrap().tick(1.);
+        world.run_system(system).unwrap();
+        assert_eq!(now(&world), 3.);
+
+        world.get_mut::<TimeScrubber>(scrubber).unwrap().scrub = 2.;
+        world.run_system(system).unwrap();
+        assert_eq!(now(&world), 4.);
+    }
+
+    #[test]
+    fn smooth_seek() {
+        struct FixedTimeSource;
+        impl TimeSource for FixedTimeSource {
+            fn delta_secs(&self, _world: &World) -> f32 {
+                0.25
+            }
+        }
+
+        let mut world = World::default();
+        world.init_resource::<Events<TimeRunnerStarted>>();
+        world.init_resource::<Events<TimeRunnerPaused>>();
+        world.init_resource::<Events<TimeRunnerResumed>>();
+        world.init_resource::<Events<TimeRunnerLooped>>();
+        world.init_resource::<Events<TimeRunnerEnded>>();
+        world.insert_resource::<Box<dyn TimeSource>>(Box::new(FixedTimeSource));
+        let runner = world
+            .spawn((
+                TimeRunner::new(Duration::from_secs(4)),
+                SmoothSeek::new(3., 4.),
+            ))
+            .id();
+        let systems = [
+            world.register_system(smooth_seek_system),
+            world.register_system(tick_time_runner_system),
+        ];
+        let runner_state = |world: &mut World| {
+            run_systems(world, &systems);
+            let runner = world.get::<TimeRunner>(runner).unwrap();
+            (runner.elasped().now(), runner.paused())
+        };
+
+        // not ticked while seeking
+        assert_eq!(runner_state(&mut world), (1., false));
+        assert_eq!(runner_state(&mut world), (2., false));
+        // ticked again once arrived
+        assert_eq!(runner_state(&mut world), (3.25, false));
+        assert!(world.get::<SmoothSeek>(runner).is_none());
+        assert_eq!(runner_state(&mut world), (3.5, false));
+        assert!(world.resource::<Events<TimeRunnerPaused>>().is_empty());
+        assert!(world.resource::<Events<TimeRunnerResumed>>().is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+};
+
+use crate::{time_source::*, TimeRunnerSet};
+
+/// Ticks every runner deterministically by [`AdvanceTime`] for testing.
+///
+/// [`AdvanceTime`] is reset to zero after every tick so runners only advance
+/// on frames where it has been set.
+/// [`TimeRunnerPlugin`](crate::TimeRunnerPlugin) is still required.
+#[derive(Debug)]
+pub struct MockTimeRunnerPlugin {
+    /// [`TimeRunnerPlugin::tick_schedule`](crate::TimeRunnerPlugin::tick_schedule)
+    pub schedule: InternedScheduleLabel,
+}
+
+impl Default for MockTimeRunnerPlugin {
+    fn default() -> Self {
+        MockTimeRunnerPlugin {
+            schedule: PostUpdate.intern(),
+        }
+    }
+}
+
+impl Plugin for MockTimeRunnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AdvanceTime>()
+            .insert_resource::<Box<dyn TimeSource>>(Box::new(AdvanceTimeSource))
+            .add_systems(
+                self.schedule,
+                reset_advance_time_system.after(TimeRunnerSet::TickTimer),
+            );
+    }
+}
+
+/// Duration to tick every runner by in the next frame when using [`MockTimeRunnerPlugin`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct AdvanceTime(pub Duration);
+
+/// [`TimeSource`] using [`AdvanceTime`]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AdvanceTimeSource;
+
+impl TimeSource for AdvanceTimeSource {
+    fn delta_secs(&self, world: &World) -> f32 {
+        world
+            .get_resource::<AdvanceTime>()
+            .map(|advance_time| advance_time.0.as_secs_f32())
+            .unwrap_or_default()
+    }
+}
+
+/// System for resetting [`AdvanceTime`] to zero after being used for ticking
+pub fn reset_advance_time_system(mut advance_time: ResMut<AdvanceTime>) {
+    advance_time.0 = Duration::ZERO;
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::hierarchy::{BuildChildren, ChildBuild};
+
+    use super::*;
+    use crate::{TimeRunner, TimeRunnerPlugin, TimeSpan, TimeSpanProgress};
+
+    fn secs(secs: f32) -> Duration {
+        Duration::from_secs_f32(secs)
+    }
+
+    #[test]
+    fn advance_time() {
+        let mut app = App::new();
+        app.add_plugins((TimeRunnerPlugin::default(), MockTimeRunnerPlugin::default()));
+        let mut span = Entity::PLACEHOLDER;
+        app.world_mut()
+            .spawn(TimeRunner::new(secs(10.)))
+            .with_children(|c| {
+                span = c
+                    .spawn(TimeSpan::try_from(secs(0.)..secs(10.)).unwrap())
+                    .id();
+            });
+        let now = |app: &App| app.world().get::<TimeSpanProgress>(span).map(|p| p.now);
+
+        app.update();
+        assert_eq!(now(&app), Some(0.));
+
+        app.insert_resource(AdvanceTime(secs(1.)));
+        app.update();
+        assert_eq!(now(&app), Some(1.));
+        assert_eq!(app.world().resource::<AdvanceTime>().0, Duration::ZERO);
+
+        app.update();
+        assert_eq!(now(&app), Some(1.));
+    }
+}
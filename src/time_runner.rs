@@ -1,16 +1,27 @@
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    entity::{EntityHashMap, EntityHashSet},
+    prelude::*,
+    query::{QueryData, QueryFilter},
+    system::{SystemParam, SystemState},
+};
 use bevy_hierarchy::prelude::*;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::prelude::*;
 use bevy_time::prelude::*;
-use std::{cmp::Ordering, time::Duration};
+use bevy_utils::Parallel;
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    time::Duration,
+};
 
-use crate::time_span::*;
+use crate::{scrub::SmoothSeek, time_source::*, time_span::*};
 
 /// Contains the current elasped time per tick.
 /// Have more informations useful for handling edge cases and retain timing accuracy.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimeRunnerElasped {
     now: f32,
     now_period: f32,
@@ -46,17 +57,94 @@ impl TimeRunnerElasped {
     pub fn previous_period(&self) -> f32 {
         self.previous_period
     }
+    /// [`TimeRunnerElasped::now`] as [`Duration`], saturating at zero if negative or NaN.
+    /// Still limited to `f32` precision, see [`TimeRunner::elasped_f64`] for more.
+    #[inline]
+    pub fn now_as_duration(&self) -> Duration {
+        Duration::from_secs_f32(self.now.max(0.))
+    }
+    /// [`TimeRunnerElasped::previous`] as [`Duration`], saturating at zero if negative or NaN.
+    /// Still limited to `f32` precision, see [`TimeRunner::elasped_f64`] for more.
+    #[inline]
+    pub fn previous_as_duration(&self) -> Duration {
+        Duration::from_secs_f32(self.previous.max(0.))
+    }
+    /// Seconds elasped since the previous tick, `now - previous`.
+    /// Negative when ticking backward.
+    #[inline]
+    pub const fn delta(&self) -> f32 {
+        self.now - self.previous
+    }
+    /// Periods elasped since the previous tick, `now_period - previous_period`.
+    #[inline]
+    pub const fn delta_period(&self) -> f32 {
+        self.now_period - self.previous_period
+    }
+}
+
+/// [`TimeRunnerElasped`] in `f64` for [`TimeRunner::with_high_precision`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeRunnerElaspedF64 {
+    now: f64,
+    now_period: f64,
+    previous: f64,
+    previous_period: f64,
+}
+
+impl TimeRunnerElaspedF64 {
+    fn update(&mut self, now: f64, now_period: f64) {
+        self.previous = self.now;
+        self.previous_period = self.now_period;
+        self.now = now;
+        self.now_period = now_period;
+    }
+
+    /// See [`TimeRunnerElasped::now`]
+    pub fn now(&self) -> f64 {
+        self.now
+    }
+    /// See [`TimeRunnerElasped::now_period`]
+    pub fn now_period(&self) -> f64 {
+        self.now_period
+    }
+    /// See [`TimeRunnerElasped::previous`]
+    pub fn previous(&self) -> f64 {
+        self.previous
+    }
+    /// See [`TimeRunnerElasped::previous_period`]
+    pub fn previous_period(&self) -> f64 {
+        self.previous_period
+    }
+}
+
+impl From<TimeRunnerElasped> for TimeRunnerElaspedF64 {
+    fn from(elasped: TimeRunnerElasped) -> Self {
+        TimeRunnerElaspedF64 {
+            now: elasped.now as f64,
+            now_period: elasped.now_period as f64,
+            previous: elasped.previous as f64,
+            previous_period: elasped.previous_period as f64,
+        }
+    }
 }
 
 /// Advanced timer
 #[derive(Debug, Clone, PartialEq, Component)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 #[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct TimeRunner {
     paused: bool,
     /// The current elasped time with other useful information.
     elasped: TimeRunnerElasped,
     /// Maximum amount of duration.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_duration"))]
     length: Duration,
     /// Ticking direction of the current timer.
     direction: TimeDirection,
@@ -64,6 +152,8 @@ pub struct TimeRunner {
     time_scale: f32,
     /// Repeat configuration.
     repeat: Option<(Repeat, RepeatStyle)>,
+    /// Elasped time kept in `f64` if high precision is enabled.
+    high_precision: Option<TimeRunnerElaspedF64>,
 }
 
 impl TimeRunner {
@@ -75,12 +165,108 @@ impl TimeRunner {
         }
     }
 
+    /// Create new [`TimeRunner`] with the length of `secs` seconds.
+    pub fn from_secs(secs: f32) -> TimeRunner {
+        TimeRunner::new(Duration::from_secs_f32(secs))
+    }
+
+    /// Create new [`TimeRunner`] with the length of `secs` seconds.
+    pub fn from_secs_f64(secs: f64) -> TimeRunner {
+        TimeRunner::new(Duration::from_secs_f64(secs))
+    }
+
+    /// Create new [`TimeRunner`] with the length of `millis` milliseconds.
+    pub fn from_millis(millis: u64) -> TimeRunner {
+        TimeRunner::new(Duration::from_millis(millis))
+    }
+
+    /// [`TimeRunner`] with this length
+    pub fn with_length(mut self, duration: Duration) -> Self {
+        self.set_length(duration);
+        self
+    }
+
+    /// [`TimeRunner`] with this paused
+    pub fn with_paused(mut self, paused: bool) -> Self {
+        self.set_paused(paused);
+        self
+    }
+
+    /// [`TimeRunner`] with this time scale
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        self.set_time_scale(time_scale);
+        self
+    }
+
+    /// [`TimeRunner`] with high precision enabled
+    pub fn with_high_precision(mut self) -> Self {
+        self.set_high_precision(true);
+        self
+    }
+
+    /// [`TimeRunner`] with this direction
+    pub fn with_direction(mut self, direction: TimeDirection) -> Self {
+        self.set_direction(direction);
+        self
+    }
+
+    /// [`TimeRunner`] with this repeat
+    pub fn with_repeat(mut self, repeat: Option<(Repeat, RepeatStyle)>) -> Self {
+        self.set_repeat(repeat);
+        self
+    }
+
+    /// Repeat for `times` with [`RepeatStyle::WrapAround`].
+    pub fn with_repeat_times(self, times: i32) -> Self {
+        self.with_repeat(Some(
+            Repeat::times(times).with_style(RepeatStyle::WrapAround),
+        ))
+    }
+
+    /// Play forward then backward once, two passes in total, with
+    /// [`Repeat::times`] of `1` and [`RepeatStyle::PingPong`].
+    /// The direction is set to [`TimeDirection::Forward`].
+    pub fn ping_pong_once(self) -> Self {
+        self.with_direction(TimeDirection::Forward)
+            .with_repeat(Some(Repeat::times(1).with_style(RepeatStyle::PingPong)))
+    }
+
     /// Set timer length
     pub fn set_length(&mut self, duration: Duration) -> &mut Self {
         self.length = duration;
         self
     }
 
+    /// Set timer length and scale the elasped time proportionally so the
+    /// elasped percentage stays the same.
+    /// The elasped time is reset to `0` if the current length is zero.
+    pub fn resize(&mut self, new_length: Duration) -> &mut Self {
+        let scale = if self.length.is_zero() {
+            0.
+        } else {
+            new_length.as_secs_f32() / self.length.as_secs_f32()
+        };
+        self.resize_with(new_length, |secs| secs * scale)
+    }
+
+    /// Set timer length and keep the elasped time in seconds, clamped within
+    /// the new length.
+    pub fn resize_preserving_elapsed_absolute(&mut self, new_length: Duration) -> &mut Self {
+        self.resize_with(new_length, |secs| secs)
+    }
+
+    fn resize_with(&mut self, new_length: Duration, map: impl Fn(f32) -> f32) -> &mut Self {
+        let length = new_length.as_secs_f32();
+        let now = map(self.elasped.now).clamp(0., length);
+        let previous = map(self.elasped.previous).clamp(0., length);
+        self.length = new_length;
+        self.elasped.now = now;
+        self.elasped.now_period = period_percentage(now, length);
+        self.elasped.previous = previous;
+        self.elasped.previous_period = period_percentage(previous, length);
+        self
+    }
+
     /// Get timer length
     pub fn length(&self) -> Duration {
         self.length
@@ -135,6 +321,117 @@ impl TimeRunner {
         self.elasped
     }
 
+    /// Get timer elasped time in `f64`.
+    /// Only more precise than [`TimeRunner::elasped`] with high precision enabled.
+    pub fn elasped_f64(&self) -> TimeRunnerElaspedF64 {
+        // the `f32` elasped time may have been set directly since the last tick
+        match self.high_precision {
+            Some(elasped) if elasped.now as f32 == self.elasped.now => elasped,
+            _ => self.elasped.into(),
+        }
+    }
+
+    /// Returns true if high precision is enabled.
+    pub fn is_high_precision(&self) -> bool {
+        self.high_precision.is_some()
+    }
+
+    /// Accumulate the elasped time in `f64` when ticking to avoid drifting in
+    /// long running timers. [`TimeRunner::elasped`] is still in `f32`.
+    ///
+    /// Setting the elasped time directly, e.g. with [`TimeRunner::set_tick`],
+    /// is still limited to `f32` precision.
+    pub fn set_high_precision(&mut self, high_precision: bool) -> &mut Self {
+        self.high_precision = high_precision.then(|| self.elasped_f64());
+        self
+    }
+
+    /// Currently elasped now as [`Duration`]
+    pub fn elapsed_duration(&self) -> Duration {
+        Duration::from_secs_f32(self.elasped.now.max(0.))
+    }
+
+    /// Value between 0–1 as percentage of elasped time within the timer's length.
+    /// Unlike [`TimeRunnerElasped::now_period`], this is always clamped and
+    /// doesn't indicate looping or repeating.
+    /// Returns `1.` if the timer length is zero.
+    pub fn elapsed_percentage(&self) -> f32 {
+        if self.length.is_zero() {
+            return 1.;
+        }
+        (self.elasped.now / self.length.as_secs_f32()).clamp(0., 1.)
+    }
+
+    /// Time left before the timer reaches the end of the current pass
+    /// in its current direction. Doesn't account for repeat.
+    /// Returns [`Duration::ZERO`] if the timer is completed.
+    pub fn remaining(&self) -> Duration {
+        if self.is_completed() {
+            return Duration::ZERO;
+        }
+        let now = Duration::from_secs_f32(self.elasped.now.max(0.));
+        match self.direction {
+            TimeDirection::Forward => self.length.saturating_sub(now),
+            TimeDirection::Backward => now,
+        }
+    }
+
+    /// Duration of the whole run including every repeat, `length × (times + 1)`.
+    /// Returns `None` if repeating infinitely.
+    pub fn total_duration(&self) -> Option<Duration> {
+        match self.repeat {
+            None => Some(self.length),
+            Some((repeat, _)) => repeat
+                .total_iterations()
+                .map(|iterations| self.length * iterations),
+        }
+    }
+
+    /// Time left before the timer completes, the current pass plus every
+    /// repeat left. Returns `None` if repeating infinitely.
+    pub fn will_complete_in(&self) -> Option<Duration> {
+        match self.repeat {
+            None => Some(self.remaining()),
+            Some((repeat, _)) => repeat
+                .times_remaining()
+                .map(|times| self.remaining() + self.length * times.max(0) as u32),
+        }
+    }
+
+    /// Value between 0–1 as progress of the whole run including every repeat,
+    /// increasing regardless of the direction of each pass.
+    /// Returns `1.` if completed or the timer length is zero.
+    ///
+    /// Returns [`f32::NAN`] if repeating infinitely.
+    pub fn completion_ratio(&self) -> f32 {
+        let (Some(total), Some(left)) = (self.total_duration(), self.will_complete_in()) else {
+            return f32::NAN;
+        };
+        if self.is_completed() || total.is_zero() {
+            return 1.;
+        }
+        1. - left.as_secs_f32() / total.as_secs_f32()
+    }
+
+    /// Seconds elasped across every completed loop plus the current one,
+    /// `times_completed × length + now`, or `length - now` for the current loop
+    /// if ticking backward so this keeps increasing during [`RepeatStyle::PingPong`].
+    ///
+    /// [`Repeat::Infinitely`] doesn't count its loops so only the current loop is returned.
+    /// Use [`TotalElapsed`] to track time regardless of the repeat.
+    pub fn elapsed_total_secs(&self) -> f32 {
+        let loops = self
+            .repeat
+            .map(|(repeat, _)| repeat.times_completed())
+            .unwrap_or(0);
+        let length = self.length.as_secs_f32();
+        let current = match self.direction {
+            TimeDirection::Forward => self.elasped.now,
+            TimeDirection::Backward => length - self.elasped.now,
+        };
+        loops as f32 * length + current
+    }
+
     /// Returns true if the timer is completed.
     /// Completed meaning that there will be no more ticking and all
     /// configured repeat is exhausted.
@@ -174,13 +471,32 @@ impl TimeRunner {
     ///
     /// Panics if `secs` is Nan.
     pub fn raw_tick(&mut self, secs: f32) {
+        self.raw_tick_repeats(secs);
+    }
+
+    /// [`TimeRunner::tick`] by a [`Duration`].
+    /// Accounted for `paused`, `time_scale` and if the timer is completed.
+    pub fn tick_duration(&mut self, delta: Duration) {
+        self.tick(delta.as_secs_f32());
+    }
+
+    /// [`TimeRunner::raw_tick`] by a [`Duration`].
+    /// Doesn't account for `paused`, `time_scale` and if the timer is completed.
+    pub fn raw_tick_duration(&mut self, delta: Duration) {
+        self.raw_tick(delta.as_secs_f32());
+    }
+
+    /// [`TimeRunner::raw_tick`] but returns the times this timer has repeated
+    /// within this tick.
+    pub(crate) fn raw_tick_repeats(&mut self, secs: f32) -> u32 {
         use RepeatStyle::*;
         use TimeDirection::*;
 
         assert!(!secs.is_nan(), "Tick seconds can't be Nan");
 
-        let length = self.length.as_secs_f32();
-        let now = self.elasped.now;
+        let length = self.length.as_secs_f64();
+        let now = self.elasped_f64().now;
+        let secs = secs as f64;
 
         let new_elasped = match self.direction {
             Forward => now + secs,
@@ -190,7 +506,7 @@ impl TimeRunner {
         let p = period_percentage(new_elasped, length);
 
         let repeat_count = p.floor() as i32;
-        let repeat_style = 'a: {
+        let (repeat_style, repeats) = 'a: {
             if let Some(r) = self.repeat.as_mut() {
                 if repeat_count != 0 {
                     let repeat_count = if self.direction == TimeDirection::Forward {
@@ -200,25 +516,38 @@ impl TimeRunner {
                     };
                     let advances = r.0.advance_counter_by(repeat_count);
                     if advances != 0 {
-                        break 'a r.1;
+                        break 'a (r.1, advances.unsigned_abs());
                     }
                 }
             }
             if new_elasped > length {
-                self.elasped.update(length, 1.);
+                self.update_elasped(length, 1.);
             } else if new_elasped < 0. {
-                self.elasped.update(0., 0.);
+                self.update_elasped(0., 0.);
             } else {
-                self.elasped.update(new_elasped, p);
+                self.update_elasped(new_elasped, p);
             };
-            return;
+            return 0;
         };
 
         let new_elasped = match repeat_style {
             WrapAround => saw_wave(new_elasped, length),
             PingPong => triangle_wave(new_elasped, length),
+            JumpBack(to) => jump_back_wave(
+                new_elasped,
+                length,
+                to.as_secs_f64().min(length),
+                self.direction,
+            ),
+            WrapAroundWithOffset(offset) => {
+                let offset = offset.get() as f64 * length;
+                match self.direction {
+                    Forward => saw_wave(new_elasped + offset, length),
+                    Backward => saw_wave(new_elasped - offset, length),
+                }
+            }
         };
-        self.elasped.update(new_elasped, p);
+        self.update_elasped(new_elasped, p);
 
         if repeat_style == RepeatStyle::PingPong {
             let new_direction = match self.direction {
@@ -227,6 +556,16 @@ impl TimeRunner {
             };
             self.direction = new_direction;
         }
+        repeats
+    }
+
+    fn update_elasped(&mut self, now: f64, now_period: f64) {
+        if self.high_precision.is_some() {
+            let mut elasped = self.elasped_f64();
+            elasped.update(now, now_period);
+            self.high_precision = Some(elasped);
+        }
+        self.elasped.update(now as f32, now_period as f32);
     }
 
     /// Set currently elasped now to `secs`.
@@ -235,12 +574,173 @@ impl TimeRunner {
         self.elasped.now_period = period_percentage(secs, self.length.as_secs_f32());
     }
 
+    /// [`TimeRunner::set_tick`] with a [`Duration`].
+    pub fn set_elapsed_duration(&mut self, duration: Duration) {
+        self.set_tick(duration.as_secs_f32());
+    }
+
+    /// Seek back to the start position, `0` if ticking forward and `length`
+    /// if ticking backward, and reset the repeat counter with [`Repeat::reset_counter`].
+    /// Doesn't affect `paused` and `time_scale`.
+    pub fn reset(&mut self) -> &mut Self {
+        if let Some((repeat, _)) = &mut self.repeat {
+            repeat.reset_counter();
+        }
+        self.reset_keep_repeat()
+    }
+
+    /// Seek back to the start position, `0` if ticking forward and `length`
+    /// if ticking backward, without touching the repeat counter.
+    /// Doesn't affect `paused` and `time_scale`.
+    pub fn reset_keep_repeat(&mut self) -> &mut Self {
+        let start = match self.direction {
+            TimeDirection::Forward => Duration::ZERO,
+            TimeDirection::Backward => self.length,
+        };
+        self.seek_to(start)
+    }
+
+    /// Pause then [`TimeRunner::reset`] so the runner stays at its start position.
+    pub fn pause_and_reset(&mut self) -> &mut Self {
+        self.set_paused(true);
+        self.reset()
+    }
+
+    /// Pause then [`TimeRunner::reset_keep_repeat`] so the runner stays at its
+    /// start position.
+    pub fn pause_and_reset_keep_repeat(&mut self) -> &mut Self {
+        self.set_paused(true);
+        self.reset_keep_repeat()
+    }
+
+    /// Set both elasped `now` and `previous` to `target`, clamped within `0..=length`.
+    ///
+    /// Unlike [`TimeRunner::set_tick`], the next [`time_runner_system`] won't see
+    /// any time between the old and the new position so no span in between will
+    /// be activated.
+    pub fn seek_to(&mut self, target: Duration) -> &mut Self {
+        self.set_tick(target.min(self.length).as_secs_f32());
+        self.collaspe_elasped();
+        self
+    }
+
+    /// [`TimeRunner::seek_to`] by percentage of `length`, clamped within 0–1.
+    pub fn seek_normalized(&mut self, t: f32) -> &mut Self {
+        self.seek_to(self.length.mul_f32(t.clamp(0., 1.)))
+    }
+
+    /// Seek to `0`, tick forward and unpause.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_time_runner::TimeRunner;
+    /// let mut runner = TimeRunner::new(Duration::from_secs(2)).with_paused(true);
+    /// runner.tick(1.);
+    /// runner.play_from_start();
+    /// assert_eq!(runner.elasped().now(), 0.);
+    /// assert!(!runner.paused());
+    /// ```
+    pub fn play_from_start(&mut self) -> &mut Self {
+        self.seek_to(Duration::ZERO)
+            .set_direction(TimeDirection::Forward)
+            .set_paused(false)
+    }
+
+    /// Seek to `length`, tick backward and unpause.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_time_runner::{TimeRunner, TimeDirection};
+    /// let mut runner = TimeRunner::new(Duration::from_secs(2));
+    /// runner.play_from_end();
+    /// assert_eq!(runner.elasped().now(), 2.);
+    /// assert_eq!(runner.direction(), TimeDirection::Backward);
+    /// runner.tick(0.5);
+    /// assert_eq!(runner.elasped().now(), 1.5);
+    /// ```
+    pub fn play_from_end(&mut self) -> &mut Self {
+        self.seek_to(self.length)
+            .set_direction(TimeDirection::Backward)
+            .set_paused(false)
+    }
+
+    /// Flip the direction and mirror the elasped time to `length - now`.
+    /// The elasped time is collasped so the next tick continues from the
+    /// mirrored position.
+    pub fn reverse(&mut self) -> &mut Self {
+        self.direction = self.direction.opposite();
+        self.set_tick(self.length.as_secs_f32() - self.elasped.now);
+        self.collaspe_elasped();
+        self
+    }
+
+    /// Capture the current playback state of this timer.
+    /// `length` is not included.
+    pub fn save_snapshot(&self) -> TimeRunnerSnapshot {
+        TimeRunnerSnapshot {
+            elasped: self.elasped,
+            direction: self.direction,
+            time_scale: self.time_scale,
+            paused: self.paused,
+            repeat: self.repeat,
+        }
+    }
+
+    /// Restore the playback state from a [`TimeRunnerSnapshot`].
+    /// The elasped time is collasped so the next tick starts from the
+    /// snapshot's `now` without any delta.
+    pub fn restore_snapshot(&mut self, snap: &TimeRunnerSnapshot) -> &mut Self {
+        self.elasped = snap.elasped;
+        self.direction = snap.direction;
+        self.time_scale = snap.time_scale;
+        self.paused = snap.paused;
+        self.repeat = snap.repeat;
+        self.collaspe_elasped();
+        self
+    }
+
+    /// Copy the elasped time of `leader` scaled to this length along with its
+    /// direction and repeat.
+    fn follow(&mut self, leader: &TimeRunner) {
+        let length = self.length.as_secs_f64();
+        let leader_length = leader.length.as_secs_f64();
+        let scale = |secs: f64| {
+            if leader_length == 0. {
+                length
+            } else {
+                secs / leader_length * length
+            }
+        };
+        let elasped = leader.elasped_f64();
+        let elasped = TimeRunnerElaspedF64 {
+            now: scale(elasped.now),
+            now_period: elasped.now_period,
+            previous: scale(elasped.previous),
+            previous_period: elasped.previous_period,
+        };
+        if self.high_precision.is_some() {
+            self.high_precision = Some(elasped);
+        }
+        self.elasped = TimeRunnerElasped {
+            now: elasped.now as f32,
+            now_period: elasped.now_period as f32,
+            previous: elasped.previous as f32,
+            previous_period: elasped.previous_period as f32,
+        };
+        self.direction = leader.direction;
+        self.repeat = leader.repeat;
+    }
+
     /// Call this method when you've handled the range of time between `previous`
     /// and `now` inside [`TimerElasped`].
     /// Set all `previous` in [`TimerElasped`] to `now`.
     pub(crate) fn collaspe_elasped(&mut self) {
         self.elasped.previous = self.elasped.now;
         self.elasped.previous_period = self.elasped.now_period;
+        if let Some(elasped) = &mut self.high_precision {
+            elasped.previous = elasped.now;
+            elasped.previous_period = elasped.now_period;
+        }
     }
 }
 
@@ -253,13 +753,32 @@ impl Default for TimeRunner {
             direction: Default::default(),
             time_scale: 1.,
             repeat: Default::default(),
+            high_precision: None,
         }
     }
 }
 
+/// Playback state of a [`TimeRunner`] saved by [`TimeRunner::save_snapshot`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeRunnerSnapshot {
+    /// Saved elasped time
+    pub elasped: TimeRunnerElasped,
+    /// Saved ticking direction
+    pub direction: TimeDirection,
+    /// Saved time scale
+    pub time_scale: f32,
+    /// Saved paused state
+    pub paused: bool,
+    /// Saved repeat configuration and counter
+    pub repeat: Option<(Repeat, RepeatStyle)>,
+}
+
 /// Timer repeat configuration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Repeat {
     /// Repeat infinitely
     Infinitely,
@@ -298,6 +817,11 @@ impl Repeat {
         }
     }
 
+    /// Pair this repeat with `style` for [`TimeRunner::set_repeat`].
+    pub fn with_style(self, style: RepeatStyle) -> (Repeat, RepeatStyle) {
+        (self, style)
+    }
+
     /// Returns if all repeat has been exhausted.
     /// Infinite repeat always returns false.
     pub fn exhausted(&self) -> bool {
@@ -311,6 +835,49 @@ impl Repeat {
         }
     }
 
+    /// Times left to repeat.
+    /// Infinite repeat always returns `None`.
+    pub fn times_remaining(&self) -> Option<i32> {
+        match self {
+            Repeat::Infinitely | Repeat::InfinitelyCounted { .. } => None,
+            Repeat::Times {
+                times,
+                times_repeated,
+            } => Some(times - times_repeated),
+        }
+    }
+
+    /// Times this timer has repeated.
+    /// [`Repeat::Infinitely`] does not count and always returns 0.
+    pub fn times_completed(&self) -> i32 {
+        match self {
+            Repeat::Infinitely => 0,
+            Repeat::InfinitelyCounted { times_repeated } | Repeat::Times { times_repeated, .. } => {
+                *times_repeated
+            }
+        }
+    }
+
+    /// Total times to run including the initial run, `times + 1`.
+    /// Infinite repeat always returns `None`.
+    pub fn total_iterations(&self) -> Option<u32> {
+        match self {
+            Repeat::Infinitely | Repeat::InfinitelyCounted { .. } => None,
+            Repeat::Times { times, .. } => Some((*times).max(0) as u32 + 1),
+        }
+    }
+
+    /// Set the times repeated back to 0 while keeping the total times.
+    /// No-op for [`Repeat::Infinitely`].
+    pub fn reset_counter(&mut self) {
+        match self {
+            Repeat::Infinitely => {}
+            Repeat::InfinitelyCounted { times_repeated } | Repeat::Times { times_repeated, .. } => {
+                *times_repeated = 0
+            }
+        }
+    }
+
     /// Returns actual advanced count.
     pub fn advance_counter_by(&mut self, by: i32) -> i32 {
         match self {
@@ -338,22 +905,109 @@ impl Repeat {
 /// Time runner repeat behavior
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RepeatStyle {
     /// Timer will wrap around.
     #[default]
     WrapAround,
     /// Timer will flip its direction.
     PingPong,
+    /// Timer will wrap back to the given time instead of the start.
+    /// Ticking backward, it wraps to `length` minus the given time instead of `length`.
+    ///
+    /// Spans entirely before the given time are only played on the first pass.
+    JumpBack(Duration),
+    /// Timer will wrap around then move forward by this fraction of `length`,
+    /// or backward if ticking backward.
+    WrapAroundWithOffset(WrapOffset),
+}
+
+impl RepeatStyle {
+    /// [`RepeatStyle::WrapAroundWithOffset`] or `None` if `offset` is not within `[0, 1)`
+    pub fn wrap_around_with_offset(offset: f32) -> Option<RepeatStyle> {
+        WrapOffset::new(offset).map(RepeatStyle::WrapAroundWithOffset)
+    }
+}
+
+/// Offset of [`RepeatStyle::WrapAroundWithOffset`] as a fraction of the timer
+/// length. Always within `[0, 1)`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "f32", into = "f32")
+)]
+pub struct WrapOffset(f32);
+
+impl WrapOffset {
+    /// Create a new [`WrapOffset`] or `None` if `offset` is not within `[0, 1)`
+    pub fn new(offset: f32) -> Option<WrapOffset> {
+        // `+ 0.` so `-0.` is stored as `0.`
+        (0. ..1.)
+            .contains(&offset)
+            .then_some(WrapOffset(offset + 0.))
+    }
+
+    /// The offset within `[0, 1)`
+    pub fn get(&self) -> f32 {
+        self.0
+    }
+}
+
+// Never NaN
+impl Eq for WrapOffset {}
+
+impl Hash for WrapOffset {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl TryFrom<f32> for WrapOffset {
+    type Error = WrapOffsetOutOfRange;
+
+    fn try_from(offset: f32) -> Result<Self, Self::Error> {
+        WrapOffset::new(offset).ok_or(WrapOffsetOutOfRange(offset))
+    }
+}
+
+impl From<WrapOffset> for f32 {
+    fn from(offset: WrapOffset) -> Self {
+        offset.0
+    }
+}
+
+/// Error for when creating a [`WrapOffset`] not within `[0, 1)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WrapOffsetOutOfRange(pub f32);
+
+impl std::error::Error for WrapOffsetOutOfRange {}
+impl std::fmt::Display for WrapOffsetOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "This offset is not within [0, 1): {}", self.0)
+    }
 }
 
-fn saw_wave(x: f32, period: f32) -> f32 {
+fn saw_wave(x: f64, period: f64) -> f64 {
     x.rem_euclid(period)
 }
 
-fn triangle_wave(x: f32, period: f32) -> f32 {
+fn triangle_wave(x: f64, period: f64) -> f64 {
     ((x + period).rem_euclid(period * 2.) - period).abs()
 }
 
+fn jump_back_wave(x: f64, period: f64, to: f64, direction: TimeDirection) -> f64 {
+    let loop_period = period - to;
+    if loop_period <= 0. {
+        return x.clamp(0., period);
+    }
+    match direction {
+        TimeDirection::Forward => to + (x - to).rem_euclid(loop_period),
+        TimeDirection::Backward => x.rem_euclid(loop_period),
+    }
+}
+
 fn triangle_wave_direction(repeats: i32) -> TimeDirection {
     if repeats.rem_euclid(2) == 0 {
         TimeDirection::Forward
@@ -363,14 +1017,10 @@ fn triangle_wave_direction(repeats: i32) -> TimeDirection {
 }
 
 fn backward_triangle_wave_direction(repeats: i32) -> TimeDirection {
-    if repeats.rem_euclid(2) == 0 {
-        TimeDirection::Backward
-    } else {
-        TimeDirection::Forward
-    }
+    triangle_wave_direction(repeats).opposite()
 }
 
-fn period_percentage(x: f32, period: f32) -> f32 {
+fn period_percentage<T: std::ops::Div<Output = T>>(x: T, period: T) -> T {
     x / period
 }
 
@@ -380,96 +1030,797 @@ fn period_percentage(x: f32, period: f32) -> f32 {
 #[cfg_attr(feature = "bevy_reflect", reflect(Component))]
 pub struct SkipTimeRunner;
 
-/// Fired when a time runner repeated or completed
+/// Marks a [`TimeRunner`] to be ticked by [`fixed_tick_time_runner_system`]
+/// using [`Time<Fixed>`] instead of the default tick system.
+#[derive(Debug, Clone, Copy, Component)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Event)]
-pub struct TimeRunnerEnded {
-    /// [`TimeRunner`] that just ended
-    pub time_runner: Entity,
-    /// Currently timer direction. If is [`RepeatStyle::PingPong`], the current
-    /// direction will be its already changed direction.
-    pub current_direction: TimeDirection,
-    /// The repeat this time runner had.
-    pub with_repeat: Option<Repeat>,
-}
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct FixedTickRunner;
 
-impl TimeRunnerEnded {
-    /// Returns true if the time runner is completed.
-    /// Completed meaning that there will be no more ticking and all
-    /// configured repeat is exhausted.
-    pub fn is_completed(&self) -> bool {
-        self.with_repeat
-            .map(|repeat| repeat.exhausted())
-            .unwrap_or(true)
+/// Multiplies the time scale of every [`TimeRunner`] without
+/// [`IgnoreGlobalTimeScale`] when ticking.
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Resource))]
+pub struct GlobalTimeScale(pub f32);
+
+impl Default for GlobalTimeScale {
+    fn default() -> Self {
+        GlobalTimeScale(1.)
     }
 }
 
-/// Tick time runner then send [`TimeRunnerEnded`] event if qualified for.
-pub fn tick_time_runner_system(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut q_time_runner: Query<(Entity, &mut TimeRunner)>,
-    mut ended_writer: EventWriter<TimeRunnerEnded>,
-) {
-    let delta = time.delta_secs();
-    q_time_runner
-        .iter_mut()
-        .for_each(|(entity, mut time_runner)| {
-            if time_runner.paused || time_runner.is_completed() {
-                return;
-            }
-            let scale = time_runner.time_scale;
-            time_runner.raw_tick(delta * scale);
-
-            let n = time_runner.elasped().now_period;
-            let send_event = match time_runner.repeat {
-                Some((_, RepeatStyle::PingPong)) => {
-                    (time_runner.direction == TimeDirection::Forward && n < 0.)
-                        || (time_runner.direction == TimeDirection::Backward && n >= 1.)
-                }
-                _ => {
-                    (time_runner.direction == TimeDirection::Backward && n < 0.)
-                        || (time_runner.direction == TimeDirection::Forward && n >= 1.)
-                }
-            };
-            if send_event {
-                let event = TimeRunnerEnded {
-                    time_runner: entity,
-                    current_direction: time_runner.direction,
-                    with_repeat: time_runner.repeat.map(|r| r.0),
-                };
-                commands.trigger_targets(event.clone(), entity);
+/// Marks a [`TimeRunner`] to not be affected by [`GlobalTimeScale`].
+#[derive(Debug, Clone, Copy, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct IgnoreGlobalTimeScale;
+
+/// Pauses every [`TimeRunner`] without [`IgnoreGlobalTimePause`] when `true`.
+///
+/// This is separated from [`TimeRunner::paused`] and doesn't send
+/// [`TimeRunnerPaused`] or [`TimeRunnerResumed`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Resource))]
+pub struct GlobalTimePause(pub bool);
+
+/// Marks a [`TimeRunner`] to not be affected by [`GlobalTimePause`].
+#[derive(Debug, Clone, Copy, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct IgnoreGlobalTimePause;
+
+/// Wait for this duration before the [`TimeRunner`] starts ticking.
+/// The delay is counted down with the unscaled delta, only while the runner
+/// is not paused, then this component removes itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct StartDelay(pub Duration);
+
+/// Wait for this duration every time the [`TimeRunner`] repeats before
+/// advancing into the next cycle.
+/// The delay is counted with the unscaled delta and doesn't apply to the first cycle.
+///
+/// The delta that overflowed into the next cycle on the repeating tick is kept.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct RepeatDelay(pub Duration);
+
+/// Remaining [`RepeatDelay`] of a runner, inserted on repeat.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct RepeatDelayState {
+    remaining: f32,
+}
+
+/// Seconds until this [`TimeRunner`] completes from [`TimeRunner::will_complete_in`],
+/// [`f32::INFINITY`] if repeating infinitely.
+///
+/// Maintained by [`time_until_complete_system`] and removed once the runner is completed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct TimeUntilComplete(pub f32);
+
+/// Progress of the whole run of this [`TimeRunner`] from
+/// [`TimeRunner::completion_ratio`], clamped to 0–1.
+///
+/// Maintained by [`time_runner_progress_system`]. Never inserted for runners
+/// repeating infinitely and kept at `1.` once the runner is completed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct TimeRunnerProgress(pub f32);
+
+/// Progress of this [`TimeRunner`] within its current repeat cycle.
+///
+/// Insert it alongside [`TimeRunner`] to be updated by [`repeat_progress_system`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct RepeatProgress {
+    /// Current elasped percentage of the cycle within 0–1
+    pub cycle_now: f32,
+    /// Previous elasped percentage of the cycle within 0–1
+    pub cycle_previous: f32,
+    /// 0-indexed number of the current cycle
+    pub cycle_index: u32,
+}
+
+/// Pause this [`TimeRunner`] once its elasped time reaches `at_secs`
+/// in its current direction, then this component removes itself.
+///
+/// Only one per runner. Insert it again after unpausing for the next pause.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct ScheduledPause {
+    /// Elasped seconds to pause at
+    pub at_secs: f32,
+}
+
+/// Total seconds a [`TimeRunner`] has advanced across every repeat.
+///
+/// Accumulated while the runner is ticked so it never resets on loops or
+/// direction changes. Only counts time actually advanced, excluding delays
+/// and overshoot past the end. Insert it alongside [`TimeRunner`] to start tracking.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct TotalElapsed(pub f32);
+
+/// Despawn this [`TimeRunner`] and its children recursively once completed.
+///
+/// Despawned in the next [`time_runner_system`] run after completion so the
+/// final [`TimeSpanProgress`] can still be read.
+#[derive(Debug, Clone, Copy, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct AutoDespawnOnComplete;
+
+/// Coarse state of a [`TimeRunner`] maintained by [`time_runner_state_system`].
+///
+/// Inserted automatically to every runner. Only written to when the state
+/// actually changes so [`Changed<TimeRunnerState>`] can be used to detect transitions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub enum TimeRunnerState {
+    /// Paused at the start position
+    #[default]
+    Idle,
+    /// Not paused and not completed
+    Running,
+    /// Paused somewhere after the start position
+    Paused,
+    /// [`TimeRunner::is_completed`] is true
+    Completed,
+}
+
+impl TimeRunnerState {
+    /// Get the current state of a [`TimeRunner`]
+    pub fn of(runner: &TimeRunner) -> TimeRunnerState {
+        if runner.is_completed() {
+            return TimeRunnerState::Completed;
+        }
+        if !runner.paused {
+            return TimeRunnerState::Running;
+        }
+        let start = match runner.direction {
+            TimeDirection::Forward => 0.,
+            TimeDirection::Backward => runner.length.as_secs_f32(),
+        };
+        if runner.elasped.now == start {
+            TimeRunnerState::Idle
+        } else {
+            TimeRunnerState::Paused
+        }
+    }
+}
+
+/// Keep `followers` at the same elasped percentage as the `leader` [`TimeRunner`].
+///
+/// Followers aren't ticked on their own. [`synced_time_runners_system`] copies
+/// the leader's elasped time scaled by each follower's length, its direction and
+/// its repeat every frame after the runners are ticked.
+#[derive(Debug, Clone, PartialEq, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct SyncedTimeRunners {
+    /// Runner to follow
+    pub leader: Entity,
+    /// Runners to follow the leader
+    pub followers: Vec<Entity>,
+}
+
+/// Fired the first time a time runner advances after being spawned, completed,
+/// reset or paused
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Event)]
+pub struct TimeRunnerStarted {
+    /// [`TimeRunner`] that just started
+    pub time_runner: Entity,
+}
+
+/// Fired when a time runner has been paused
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Event)]
+pub struct TimeRunnerPaused {
+    /// [`TimeRunner`] that just paused
+    pub time_runner: Entity,
+}
+
+/// Fired when a time runner has been unpaused
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Event)]
+pub struct TimeRunnerResumed {
+    /// [`TimeRunner`] that just resumed
+    pub time_runner: Entity,
+}
+
+/// Fired when a time runner repeated but not yet completed
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Event)]
+pub struct TimeRunnerLooped {
+    /// [`TimeRunner`] that just looped
+    pub time_runner: Entity,
+    /// 0-indexed number of the loop that just ended
+    pub loop_index: u32,
+    /// Currently timer direction. If is [`RepeatStyle::PingPong`], the current
+    /// direction will be its already changed direction.
+    pub current_direction: TimeDirection,
+}
+
+/// Triggered on the [`TimeRunner`] entity by [`time_runner_system`] once the
+/// runner is completed.
+///
+/// Only available to observers, use [`TimeRunnerEnded`] for an [`EventReader`].
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Event)]
+pub struct OnTimeRunnerCompleted;
+
+/// Fired when a time runner completed.
+/// Repeating is fired as [`TimeRunnerLooped`] instead.
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Event)]
+pub struct TimeRunnerEnded {
+    /// [`TimeRunner`] that just ended
+    pub time_runner: Entity,
+    /// 0-indexed number of the loop that just ended, the same count as
+    /// [`TimeRunnerLooped::loop_index`]. `0` if the runner never repeated.
+    #[doc(alias = "repetition_number")]
+    pub loop_index: u32,
+    /// Currently timer direction. If is [`RepeatStyle::PingPong`], the current
+    /// direction will be its already changed direction.
+    pub current_direction: TimeDirection,
+    /// The repeat this time runner had.
+    pub with_repeat: Option<Repeat>,
+}
+
+impl TimeRunnerEnded {
+    /// Returns true if the time runner is completed.
+    /// Completed meaning that there will be no more ticking and all
+    /// configured repeat is exhausted.
+    pub fn is_completed(&self) -> bool {
+        self.with_repeat
+            .map(|repeat| repeat.exhausted())
+            .unwrap_or(true)
+    }
+}
+
+/// Tick time runner using the [`TimeSource`] resource then send [`TimeRunnerEnded`]
+/// event if qualified for.
+///
+/// Uses [`VirtualTimeSource`] if there's no [`TimeSource`] resource.
+/// Runners with [`SmoothSeek`] aren't ticked.
+///
+/// This is an exclusive system since [`TimeSource::delta_secs`] may read anything
+/// from the [`World`], use [`TickTimeRunners`] in a regular system to tick with
+/// a known delta instead.
+pub fn tick_time_runner_system(
+    world: &mut World,
+    state: &mut SystemState<TickTimeRunners<(Without<FixedTickRunner>, Without<SmoothSeek>)>>,
+) {
+    let delta = time_source_delta(world);
+    state.get_mut(world).tick(delta);
+    state.apply(world);
+}
+
+/// Tick time runner with [`FixedTickRunner`] using [`Time<Fixed>`] then send
+/// [`TimeRunnerEnded`] event if qualified for.
+/// Runners with [`SmoothSeek`] aren't ticked.
+pub fn fixed_tick_time_runner_system(
+    time: Res<Time<Fixed>>,
+    mut tick_time_runners: TickTimeRunners<(With<FixedTickRunner>, Without<SmoothSeek>)>,
+) {
+    tick_time_runners.tick(time.delta_secs());
+}
+
+/// System for copying the elasped time of a [`SyncedTimeRunners`] leader
+/// to its followers.
+pub fn synced_time_runners_system(
+    q_synced: Query<&SyncedTimeRunners>,
+    mut q_runner: Query<&mut TimeRunner>,
+) {
+    for synced in &q_synced {
+        let Ok(leader) = q_runner.get(synced.leader).cloned() else {
+            continue;
+        };
+        for &follower in &synced.followers {
+            if follower == synced.leader {
+                continue;
+            }
+            let Ok(mut follower) = q_runner.get_mut(follower) else {
+                continue;
+            };
+            let mut followed = follower.clone();
+            followed.follow(&leader);
+            if *follower != followed {
+                *follower = followed;
+            }
+        }
+    }
+}
+
+/// System for updating [`RepeatProgress`] of every [`TimeRunner`].
+/// Each [`TimeRunnerLooped`] advances the cycle index.
+pub fn repeat_progress_system(
+    mut q_runner: Query<(Entity, &TimeRunner, &mut RepeatProgress)>,
+    mut looped_reader: EventReader<TimeRunnerLooped>,
+) {
+    let mut cycle_indices = EntityHashMap::<u32>::default();
+    for event in looped_reader.read() {
+        cycle_indices.insert(event.time_runner, event.loop_index + 1);
+    }
+    for (entity, runner, mut repeat_progress) in &mut q_runner {
+        let previous = if runner.length().is_zero() {
+            1.
+        } else {
+            (runner.elasped().previous / runner.length().as_secs_f32()).clamp(0., 1.)
+        };
+        repeat_progress.set_if_neq(RepeatProgress {
+            cycle_now: runner.elapsed_percentage(),
+            cycle_previous: previous,
+            cycle_index: cycle_indices
+                .get(&entity)
+                .copied()
+                .unwrap_or(repeat_progress.cycle_index),
+        });
+    }
+}
+
+/// Run condition that's true if the [`TimeRunner`] of `entity` is completed.
+/// False if there's no such runner.
+pub fn is_time_runner_completed(entity: Entity) -> impl FnMut(Query<&TimeRunner>) -> bool + Clone {
+    move |q_runner: Query<&TimeRunner>| {
+        q_runner
+            .get(entity)
+            .is_ok_and(|runner| runner.is_completed())
+    }
+}
+
+/// Run condition that's true if the [`TimeRunner`] of `entity` is paused.
+/// False if there's no such runner.
+pub fn is_time_runner_paused(entity: Entity) -> impl FnMut(Query<&TimeRunner>) -> bool + Clone {
+    move |q_runner: Query<&TimeRunner>| q_runner.get(entity).is_ok_and(|runner| runner.paused())
+}
+
+/// System for inserting, updating and removing [`TimeUntilComplete`] of every [`TimeRunner`]
+pub fn time_until_complete_system(
+    mut commands: Commands,
+    mut q_runner: Query<(Entity, &TimeRunner, Option<&mut TimeUntilComplete>)>,
+) {
+    for (entity, runner, time_until_complete) in &mut q_runner {
+        if runner.is_completed() {
+            if time_until_complete.is_some() {
+                commands.entity(entity).remove::<TimeUntilComplete>();
+            }
+            continue;
+        }
+        let secs = runner
+            .will_complete_in()
+            .map(|d| d.as_secs_f32())
+            .unwrap_or(f32::INFINITY);
+        match time_until_complete {
+            Some(mut time_until_complete) => {
+                time_until_complete.set_if_neq(TimeUntilComplete(secs));
+            }
+            None => {
+                commands.entity(entity).insert(TimeUntilComplete(secs));
+            }
+        }
+    }
+}
+
+/// System for inserting, updating and removing [`TimeRunnerProgress`] of every [`TimeRunner`]
+pub fn time_runner_progress_system(
+    mut commands: Commands,
+    mut q_runner: Query<(Entity, &TimeRunner, Option<&mut TimeRunnerProgress>)>,
+) {
+    for (entity, runner, progress) in &mut q_runner {
+        let ratio = runner.completion_ratio();
+        match (progress, ratio.is_nan()) {
+            (Some(_), true) => {
+                commands.entity(entity).remove::<TimeRunnerProgress>();
+            }
+            (None, true) => {}
+            (Some(mut progress), false) => {
+                progress.set_if_neq(TimeRunnerProgress(ratio.clamp(0., 1.)));
+            }
+            (None, false) => {
+                commands
+                    .entity(entity)
+                    .insert(TimeRunnerProgress(ratio.clamp(0., 1.)));
+            }
+        }
+    }
+}
+
+/// System for pausing runners that reached their [`ScheduledPause`]
+pub fn scheduled_pause_system(
+    mut commands: Commands,
+    mut q_runner: Query<(Entity, &mut TimeRunner, &ScheduledPause)>,
+) {
+    for (entity, mut runner, scheduled_pause) in &mut q_runner {
+        let now = runner.elasped.now;
+        let reached = match runner.direction {
+            TimeDirection::Forward => now >= scheduled_pause.at_secs,
+            TimeDirection::Backward => now <= scheduled_pause.at_secs,
+        };
+        if reached {
+            runner.set_paused(true);
+            commands.entity(entity).remove::<ScheduledPause>();
+        }
+    }
+}
+
+/// System for inserting and updating [`TimeRunnerState`] of every [`TimeRunner`]
+pub fn time_runner_state_system(
+    mut commands: Commands,
+    mut q_runner: Query<(Entity, &TimeRunner, Option<&mut TimeRunnerState>)>,
+) {
+    for (entity, runner, state) in &mut q_runner {
+        let new_state = TimeRunnerState::of(runner);
+        match state {
+            Some(mut state) => {
+                state.set_if_neq(new_state);
+            }
+            None => {
+                commands.entity(entity).insert(new_state);
+            }
+        }
+    }
+}
+
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub(crate) struct TickTimeRunnerQuery {
+    entity: Entity,
+    time_runner: &'static mut TimeRunner,
+    repeat_delay: Option<&'static RepeatDelay>,
+    repeat_delay_state: Option<&'static mut RepeatDelayState>,
+    start_delay: Option<&'static mut StartDelay>,
+    ignore_global_time_scale: Has<IgnoreGlobalTimeScale>,
+    ignore_global_time_pause: Has<IgnoreGlobalTimePause>,
+    total_elapsed: Option<&'static mut TotalElapsed>,
+}
+
+/// [`SystemParam`] for ticking every [`TimeRunner`] matching the filter `F`
+/// and sending [`TimeRunnerStarted`], [`TimeRunnerPaused`], [`TimeRunnerResumed`],
+/// [`TimeRunnerLooped`] and [`TimeRunnerEnded`] event if qualified for.
+///
+/// Followers of [`SyncedTimeRunners`] are skipped.
+#[derive(SystemParam)]
+pub struct TickTimeRunners<'w, 's, F: QueryFilter + 'static> {
+    commands: Commands<'w, 's>,
+    q_time_runner: Query<'w, 's, TickTimeRunnerQuery, F>,
+    q_synced: Query<'w, 's, &'static SyncedTimeRunners>,
+    started_writer: EventWriter<'w, TimeRunnerStarted>,
+    paused_writer: EventWriter<'w, TimeRunnerPaused>,
+    resumed_writer: EventWriter<'w, TimeRunnerResumed>,
+    looped_writer: EventWriter<'w, TimeRunnerLooped>,
+    ended_writer: EventWriter<'w, TimeRunnerEnded>,
+    global_time_scale: Option<Res<'w, GlobalTimeScale>>,
+    global_time_pause: Option<Res<'w, GlobalTimePause>>,
+    started: Local<'s, EntityHashSet>,
+    paused_states: Local<'s, EntityHashMap<bool>>,
+    loop_counts: Local<'s, EntityHashMap<u32>>,
+}
+
+impl<F: QueryFilter + 'static> TickTimeRunners<'_, '_, F> {
+    /// Tick all matching runners by `delta` seconds.
+    pub fn tick(&mut self, delta: f32) {
+        let TickTimeRunners {
+            commands,
+            q_time_runner,
+            q_synced,
+            started_writer,
+            paused_writer,
+            resumed_writer,
+            looped_writer,
+            ended_writer,
+            global_time_scale,
+            global_time_pause,
+            started,
+            paused_states,
+            loop_counts,
+        } = self;
+        let global_time_scale = global_time_scale
+            .as_ref()
+            .map(|scale| scale.0)
+            .unwrap_or(1.);
+        let global_time_paused = global_time_pause.as_ref().is_some_and(|pause| pause.0);
+        started.retain(|entity| q_time_runner.contains(*entity));
+        paused_states.retain(|entity, _| q_time_runner.contains(*entity));
+        loop_counts.retain(|entity, _| q_time_runner.contains(*entity));
+        // Followers are driven by `synced_time_runners_system`
+        let followers = q_synced
+            .iter()
+            .flat_map(|synced| {
+                synced
+                    .followers
+                    .iter()
+                    .filter(|follower| **follower != synced.leader)
+            })
+            .copied()
+            .collect::<EntityHashSet>();
+        q_time_runner.iter_mut().for_each(|item| {
+            let TickTimeRunnerQueryItem {
+                entity,
+                mut time_runner,
+                repeat_delay,
+                repeat_delay_state,
+                start_delay,
+                ignore_global_time_scale,
+                ignore_global_time_pause,
+                total_elapsed,
+            } = item;
+            if followers.contains(&entity) {
+                return;
+            }
+            let paused = time_runner.paused;
+            match paused_states.insert(entity, paused) {
+                Some(false) if paused => {
+                    let event = TimeRunnerPaused {
+                        time_runner: entity,
+                    };
+                    commands.trigger_targets(event.clone(), entity);
+                    paused_writer.send(event);
+                }
+                Some(true) if !paused => {
+                    let event = TimeRunnerResumed {
+                        time_runner: entity,
+                    };
+                    commands.trigger_targets(event.clone(), entity);
+                    resumed_writer.send(event);
+                }
+                _ => {}
+            }
+
+            if time_runner.is_completed() {
+                started.remove(&entity);
+                loop_counts.remove(&entity);
+                return;
+            }
+            let at_start = time_runner
+                .repeat
+                .is_none_or(|(repeat, _)| repeat.times_completed() == 0)
+                && match time_runner.direction {
+                    TimeDirection::Forward => time_runner.elasped.now_period <= 0.,
+                    TimeDirection::Backward => time_runner.elasped.now_period >= 1.,
+                };
+            if time_runner.paused || at_start {
+                started.remove(&entity);
+            }
+            if time_runner.paused || (global_time_paused && !ignore_global_time_pause) {
+                return;
+            }
+            let mut delta = delta;
+            if let Some(mut start_delay) = start_delay {
+                let remaining = start_delay.0.as_secs_f32();
+                if remaining > delta {
+                    start_delay.0 = start_delay
+                        .0
+                        .saturating_sub(Duration::from_secs_f32(delta.max(0.)));
+                    return;
+                }
+                delta -= remaining;
+                commands.entity(entity).remove::<StartDelay>();
+            }
+            if let Some(mut repeat_delay_state) = repeat_delay_state {
+                match repeat_delay {
+                    Some(_) if repeat_delay_state.remaining > delta => {
+                        repeat_delay_state.remaining -= delta;
+                        time_runner.raw_tick_repeats(0.);
+                        return;
+                    }
+                    Some(_) => delta -= repeat_delay_state.remaining,
+                    None => {}
+                }
+                commands.entity(entity).remove::<RepeatDelayState>();
+            }
+            let scale = if ignore_global_time_scale {
+                time_runner.time_scale
+            } else {
+                time_runner.time_scale * global_time_scale
+            };
+            let max_advance = time_runner
+                .will_complete_in()
+                .map_or(f32::INFINITY, |left| left.as_secs_f32());
+            let repeats = time_runner.raw_tick_repeats(delta * scale);
+
+            let elasped = time_runner.elasped();
+            if let Some(mut total_elapsed) = total_elapsed {
+                // Accumulated here since the advance of this tick is collasped
+                // by `time_runner_system` before any later set could read it.
+                total_elapsed.0 += if repeats > 0 {
+                    (delta * scale).abs().min(max_advance)
+                } else {
+                    (elasped.now - elasped.previous).abs()
+                };
+            }
+            let advanced =
+                elasped.now != elasped.previous || elasped.now_period != elasped.previous_period;
+            if advanced && started.insert(entity) {
+                let event = TimeRunnerStarted {
+                    time_runner: entity,
+                };
+                commands.trigger_targets(event.clone(), entity);
+                started_writer.send(event);
+            }
+
+            if repeats > 0 {
+                let loop_count = loop_counts.entry(entity).or_default();
+                for _ in 0..repeats {
+                    let event = TimeRunnerLooped {
+                        time_runner: entity,
+                        loop_index: *loop_count,
+                        current_direction: time_runner.direction,
+                    };
+                    commands.trigger_targets(event.clone(), entity);
+                    looped_writer.send(event);
+                    *loop_count += 1;
+                }
+                if let Some(repeat_delay) = repeat_delay {
+                    if !repeat_delay.0.is_zero() {
+                        commands.entity(entity).insert(RepeatDelayState {
+                            remaining: repeat_delay.0.as_secs_f32(),
+                        });
+                    }
+                }
+                return;
+            }
+
+            let at_edge = match time_runner.direction {
+                TimeDirection::Forward => elasped.now_period >= 1.,
+                TimeDirection::Backward => elasped.now_period <= 0.,
+            };
+            let exhausted = time_runner
+                .repeat
+                .map(|(repeat, _)| repeat.exhausted())
+                .unwrap_or(true);
+            if at_edge && exhausted {
+                let event = TimeRunnerEnded {
+                    time_runner: entity,
+                    loop_index: loop_counts.get(&entity).copied().unwrap_or(0),
+                    current_direction: time_runner.direction,
+                    with_repeat: time_runner.repeat.map(|r| r.0),
+                };
+                commands.trigger_targets(event.clone(), entity);
                 ended_writer.send(event);
             }
         });
+    }
+}
+
+/// Span changes computed by [`time_runner_system`] in parallel then applied
+/// after every runner is visited.
+///
+/// Only public to be used as a [`Local`] of [`time_runner_system`].
+#[doc(hidden)]
+#[derive(Default)]
+pub struct ProgressUpdates(Parallel<Vec<ProgressUpdate>>);
+
+enum ProgressUpdate {
+    Update {
+        span: Entity,
+        now: f32,
+        now_percentage: f32,
+        state: TimeSpanState,
+    },
+    Insert {
+        span: Entity,
+        runner: Entity,
+        progress: TimeSpanProgress,
+        state: TimeSpanState,
+    },
+    Remove {
+        span: Entity,
+        runner: Entity,
+        had_progress: bool,
+        state: TimeSpanState,
+    },
+    Completed(Entity),
 }
 
 /// System for updating any [`TimeSpan`] with the correct [`TimeSpanProgress`]
-/// by their runner
+/// by their runner.
+///
+/// Only runners that has been changed since the last run are updated so spans of
+/// paused runners are left untouched. A runner is still updated if any of its spans
+/// was added, changed or moved to it, or if [`SkipTimeRunner`] or [`SkipTimeSpan`]
+/// was removed.
+///
+/// A [point span](TimeSpan::is_point) has an infinite percentage once reached,
+/// negative if reached while ticking backward.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
 pub fn time_runner_system(
     mut commands: Commands,
-    mut q_runner: Query<(Entity, &mut TimeRunner, Option<&Children>), Without<SkipTimeRunner>>,
-    mut q_span: Query<(Entity, Option<&mut TimeSpanProgress>, &TimeSpan)>,
+    mut q_runner: Query<
+        (
+            Entity,
+            &mut TimeRunner,
+            Option<&Children>,
+            Has<AutoDespawnOnComplete>,
+        ),
+        Without<SkipTimeRunner>,
+    >,
+    mut q_span: Query<
+        (
+            Entity,
+            Option<&mut TimeSpanProgress>,
+            &TimeSpan,
+            Option<&mut TimeSpanState>,
+        ),
+        Without<SkipTimeSpan>,
+    >,
     q_added_skip: Query<(Entity, &TimeRunner, Option<&Children>), Added<SkipTimeRunner>>,
+    q_added_skip_span: Query<
+        (Entity, Option<&Parent>),
+        (Added<SkipTimeSpan>, With<TimeSpan>, With<TimeSpanProgress>),
+    >,
+    q_changed_span: Query<
+        (Entity, Option<&Parent>),
+        (With<TimeSpan>, Or<(Changed<TimeSpan>, Changed<Parent>)>),
+    >,
+    q_parent: Query<&Parent>,
+    mut removed_skip: RemovedComponents<SkipTimeRunner>,
+    mut removed_skip_span: RemovedComponents<SkipTimeSpan>,
     mut runner_just_completed: Local<Vec<Entity>>,
+    mut updates: Local<ProgressUpdates>,
+    mut entered_writer: EventWriter<TimeSpanEntered>,
+    mut exited_writer: EventWriter<TimeSpanExited>,
 ) {
-    use DurationQuotient::*;
-    use RepeatStyle::*;
-    use TimeDirection::*;
+    // Runners to update even if unchanged, a span may belong to its parent or itself
+    let outdated_runners = q_changed_span
+        .iter()
+        .flat_map(|(span, parent)| [Some(span), parent.map(Parent::get)])
+        .chain(
+            removed_skip_span
+                .read()
+                .flat_map(|span| [Some(span), q_parent.get(span).ok().map(Parent::get)]),
+        )
+        .flatten()
+        .chain(removed_skip.read())
+        .collect::<EntityHashSet>();
 
     let mut just_completed_runners = q_runner.iter_many(&runner_just_completed);
-    while let Some((runner_entity, runner, children)) = just_completed_runners.fetch_next() {
+    while let Some((runner_entity, runner, children, auto_despawn)) =
+        just_completed_runners.fetch_next()
+    {
         if !runner.is_completed() {
             continue;
         }
+        #[cfg(feature = "tracing")]
+        bevy_utils::tracing::debug!(runner = ?runner_entity, "removing time span progress of completed runner");
 
         let children = children.iter().flat_map(|a| a.iter());
         let mut spans = q_span.iter_many_mut([&runner_entity].into_iter().chain(children));
-        while let Some((span_entity, _, _)) = spans.fetch_next() {
+        // spans at the very end are still inside but the runner is done with them
+        let completed_state = match runner.direction {
+            TimeDirection::Forward => TimeSpanState::After,
+            TimeDirection::Backward => TimeSpanState::Before,
+        };
+        while let Some((span_entity, time_span_progress, _, state)) = spans.fetch_next() {
             let Some(mut entity) = commands.get_entity(span_entity) else {
                 continue;
             };
             entity.remove::<TimeSpanProgress>();
+            set_span_state(&mut commands, span_entity, state, completed_state);
+            if time_span_progress.is_some() {
+                let event = TimeSpanExited {
+                    span: span_entity,
+                    runner: runner_entity,
+                };
+                commands.trigger_targets(event.clone(), span_entity);
+                exited_writer.send(event);
+            }
+        }
+        if auto_despawn {
+            commands.entity(runner_entity).despawn_recursive();
         }
     }
     runner_just_completed.clear();
@@ -479,490 +1830,2316 @@ pub fn time_runner_system(
         .for_each(|(runner_entity, _, children)| {
             let children = children.iter().flat_map(|a| a.iter());
             let mut spans = q_span.iter_many_mut([&runner_entity].into_iter().chain(children));
-            while let Some((span_entity, _, _)) = spans.fetch_next() {
+            while let Some((span_entity, time_span_progress, _, _)) = spans.fetch_next() {
                 let Some(mut entity) = commands.get_entity(span_entity) else {
                     continue;
                 };
                 entity.remove::<TimeSpanProgress>();
+                if time_span_progress.is_some() {
+                    let event = TimeSpanExited {
+                        span: span_entity,
+                        runner: runner_entity,
+                    };
+                    commands.trigger_targets(event.clone(), span_entity);
+                    exited_writer.send(event);
+                }
             }
         });
 
+    q_added_skip_span.iter().for_each(|(span_entity, parent)| {
+        commands.entity(span_entity).remove::<TimeSpanProgress>();
+        let runner_entity = parent
+            .map(Parent::get)
+            .filter(|parent| q_runner.contains(*parent))
+            .or(Some(span_entity).filter(|span| q_runner.contains(*span)));
+        if let Some(runner_entity) = runner_entity {
+            let event = TimeSpanExited {
+                span: span_entity,
+                runner: runner_entity,
+            };
+            commands.trigger_targets(event.clone(), span_entity);
+            exited_writer.send(event);
+        }
+    });
+
     q_runner
-        .iter_mut()
-        .for_each(|(runner_entity, mut runner, children)| {
-            if runner.is_completed() {
+        .par_iter_mut()
+        .for_each(|(runner_entity, mut runner, children, _)| {
+            // Same as a `Changed<TimeRunner>` filter but still allows completed
+            // runners to be looked up above.
+            if runner.is_completed()
+                || !(runner.is_changed() || outdated_runners.contains(&runner_entity))
+            {
                 return;
             }
-
-            let repeated =
-                if runner.elasped().now_period.floor() as i32 != 0 && !runner.is_completed() {
-                    runner.repeat.map(|r| r.1)
-                } else {
-                    None
-                };
-
-            let runner_elasped_now = runner.elasped().now;
-            let runner_elasped_previous = runner.elasped().previous;
-            let runner_direction = runner.direction;
+            let mut updates = updates.0.borrow_local_mut();
 
             let children = children.iter().flat_map(|a| a.iter());
-            let mut spans = q_span.iter_many_mut([&runner_entity].into_iter().chain(children));
-            while let Some((span_entity, time_span_progress, span)) = spans.fetch_next() {
-                let now_quotient = span.quotient(runner_elasped_now);
-                let previous_quotient = span.quotient(runner_elasped_previous);
-
-                let direction = if repeated.is_none() {
-                    match runner_elasped_previous.total_cmp(&runner_elasped_now) {
-                        Ordering::Less => TimeDirection::Forward,
-                        Ordering::Equal => runner_direction,
-                        Ordering::Greater => TimeDirection::Backward,
-                    }
-                } else {
-                    runner_direction
-                };
+            let spans = q_span.iter_many([&runner_entity].into_iter().chain(children));
+            for (span_entity, time_span_progress, span, _) in spans {
+                updates.push(progress_update(
+                    runner_entity,
+                    &runner,
+                    span_entity,
+                    span,
+                    time_span_progress.is_some(),
+                ));
+            }
+            runner.bypass_change_detection().collaspe_elasped();
+            if runner.is_completed() {
+                updates.push(ProgressUpdate::Completed(runner_entity));
+            }
+        });
+
+    for update in updates.0.drain() {
+        match update {
+            ProgressUpdate::Update {
+                span,
+                now,
+                now_percentage,
+                state,
+            } => {
+                if let Ok((_, Some(mut progress), _, span_state)) = q_span.get_mut(span) {
+                    progress.update(now, now_percentage);
+                    set_span_state(&mut commands, span, span_state, state);
+                }
+            }
+            ProgressUpdate::Insert {
+                span,
+                runner,
+                progress,
+                state,
+            } => {
+                commands.entity(span).insert(progress);
+                if let Ok((.., span_state)) = q_span.get_mut(span) {
+                    set_span_state(&mut commands, span, span_state, state);
+                }
+                let event = TimeSpanEntered { span, runner };
+                commands.trigger_targets(event.clone(), span);
+                entered_writer.send(event);
+            }
+            ProgressUpdate::Remove {
+                span,
+                runner,
+                had_progress,
+                state,
+            } => {
+                commands.entity(span).remove::<TimeSpanProgress>();
+                if let Ok((.., span_state)) = q_span.get_mut(span) {
+                    set_span_state(&mut commands, span, span_state, state);
+                }
+                if had_progress {
+                    let event = TimeSpanExited { span, runner };
+                    commands.trigger_targets(event.clone(), span);
+                    exited_writer.send(event);
+                }
+            }
+            ProgressUpdate::Completed(runner) => {
+                commands.trigger_targets(OnTimeRunnerCompleted, runner);
+                runner_just_completed.push(runner);
+            }
+        }
+    }
+}
+
+pub(crate) fn set_span_state(
+    commands: &mut Commands,
+    span: Entity,
+    state: Option<Mut<TimeSpanState>>,
+    new_state: TimeSpanState,
+) {
+    match state {
+        Some(mut state) => {
+            state.set_if_neq(new_state);
+        }
+        None => {
+            commands.entity(span).insert(new_state);
+        }
+    }
+}
+
+/// Associate this [`TimeSpan`] with a [`TimeRunner`] without being its child.
+///
+/// Updated by [`time_runner_ref_system`] the same way as children spans are
+/// updated by [`time_runner_system`]. A span shouldn't be both a child and a reference
+/// of a runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct TimeSpanRef(pub Entity);
+
+/// System for updating any [`TimeSpan`] with [`TimeSpanRef`] with the correct
+/// [`TimeSpanProgress`] by their referenced runner.
+///
+/// Must run before [`time_runner_system`] in the same frame.
+///
+/// Spans are updated when their runner changed, or when the span itself was
+/// added, changed or had [`SkipTimeSpan`] removed.
+#[allow(clippy::type_complexity)]
+pub fn time_runner_ref_system(
+    mut commands: Commands,
+    q_runner: Query<Ref<TimeRunner>, Without<SkipTimeRunner>>,
+    mut q_span: Query<
+        (
+            Entity,
+            Ref<TimeSpanRef>,
+            Option<&mut TimeSpanProgress>,
+            Ref<TimeSpan>,
+            Option<&mut TimeSpanState>,
+        ),
+        Without<SkipTimeSpan>,
+    >,
+    mut removed_skip_span: RemovedComponents<SkipTimeSpan>,
+    mut entered_writer: EventWriter<TimeSpanEntered>,
+    mut exited_writer: EventWriter<TimeSpanExited>,
+) {
+    let unskipped = removed_skip_span.read().collect::<EntityHashSet>();
+    for (span_entity, span_ref, progress, span, span_state) in &mut q_span {
+        let runner_entity = span_ref.0;
+        let Ok(runner) = q_runner.get(runner_entity) else {
+            continue;
+        };
+        let update = if runner.is_completed() {
+            // Completed runners are not ticked anymore so this happens on the
+            // frame after the runner completed.
+            ProgressUpdate::Remove {
+                span: span_entity,
+                runner: runner_entity,
+                had_progress: progress.is_some(),
+                state: match runner.direction {
+                    TimeDirection::Forward => TimeSpanState::After,
+                    TimeDirection::Backward => TimeSpanState::Before,
+                },
+            }
+        } else if runner.is_changed()
+            || span.is_changed()
+            || span_ref.is_changed()
+            || unskipped.contains(&span_entity)
+        {
+            progress_update(
+                runner_entity,
+                &runner,
+                span_entity,
+                &span,
+                progress.is_some(),
+            )
+        } else {
+            continue;
+        };
+        let state = match &update {
+            ProgressUpdate::Update { state, .. }
+            | ProgressUpdate::Insert { state, .. }
+            | ProgressUpdate::Remove { state, .. } => *state,
+            ProgressUpdate::Completed(_) => unreachable!(),
+        };
+        set_span_state(&mut commands, span_entity, span_state, state);
+        match (update, progress) {
+            (
+                ProgressUpdate::Update {
+                    now,
+                    now_percentage,
+                    ..
+                },
+                Some(mut progress),
+            ) => {
+                progress.update(now, now_percentage);
+            }
+            (ProgressUpdate::Insert { progress, .. }, _) => {
+                commands.entity(span_entity).insert(progress);
+                let event = TimeSpanEntered {
+                    span: span_entity,
+                    runner: runner_entity,
+                };
+                commands.trigger_targets(event.clone(), span_entity);
+                entered_writer.send(event);
+            }
+            (ProgressUpdate::Remove { .. }, Some(_)) => {
+                commands.entity(span_entity).remove::<TimeSpanProgress>();
+                let event = TimeSpanExited {
+                    span: span_entity,
+                    runner: runner_entity,
+                };
+                commands.trigger_targets(event.clone(), span_entity);
+                exited_writer.send(event);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// System for removing [`TimeSpanProgress`] from spans whose [`TimeRunner`]
+/// was removed or despawned without its children.
+///
+/// Spans are found by their [`Parent`] or [`TimeSpanRef`]. No [`TimeSpanExited`]
+/// is sent since the runner doesn't exist anymore.
+#[allow(clippy::type_complexity)]
+pub fn time_runner_cleanup_system(
+    mut commands: Commands,
+    mut removed_runners: RemovedComponents<TimeRunner>,
+    q_span: Query<
+        (Entity, Option<&Parent>, Option<&TimeSpanRef>),
+        (With<TimeSpan>, With<TimeSpanProgress>),
+    >,
+) {
+    let removed = removed_runners.read().collect::<EntityHashSet>();
+    if removed.is_empty() {
+        return;
+    }
+    for (span_entity, parent, span_ref) in &q_span {
+        let orphaned = parent.is_some_and(|parent| removed.contains(&parent.get()))
+            || span_ref.is_some_and(|span_ref| removed.contains(&span_ref.0));
+        if orphaned {
+            commands.entity(span_entity).remove::<TimeSpanProgress>();
+        }
+    }
+}
+
+/// Progress of `span` from the current elasped time of `runner`.
+fn progress_update(
+    runner_entity: Entity,
+    runner: &TimeRunner,
+    span_entity: Entity,
+    span: &TimeSpan,
+    had_progress: bool,
+) -> ProgressUpdate {
+    use TimeDirection::*;
+
+    let repeated = if runner.elasped().now_period.floor() as i32 != 0 && !runner.is_completed() {
+        runner.repeat.map(|r| r.1)
+    } else {
+        None
+    };
+    // Spans that the wrap skips over aren't repeated at all
+    let wrap_to = match repeated {
+        Some(RepeatStyle::JumpBack(to)) => Some(to),
+        Some(RepeatStyle::WrapAroundWithOffset(offset)) => {
+            Some(runner.length().mul_f32(offset.get()))
+        }
+        _ => None,
+    };
+    let repeated = match wrap_to {
+        Some(to) => {
+            let skipped = match runner.direction {
+                Forward => span.max().duration() <= to,
+                Backward => span.min().duration() >= runner.length().saturating_sub(to),
+            };
+            (!skipped).then_some(RepeatStyle::WrapAround)
+        }
+        None => repeated,
+    };
+
+    let runner_elasped_now = runner.elasped().now;
+    let runner_elasped_previous = runner.elasped().previous;
+    let runner_direction = runner.direction;
+
+    let now_quotient = span.quotient(runner_elasped_now);
+    let previous_quotient = span.quotient(runner_elasped_previous);
+
+    let direction = if repeated.is_none() {
+        match runner_elasped_previous.total_cmp(&runner_elasped_now) {
+            Ordering::Less => TimeDirection::Forward,
+            Ordering::Equal => runner_direction,
+            Ordering::Greater => TimeDirection::Backward,
+        }
+    } else {
+        runner_direction
+    };
+
+    let Some(use_time) = span_in_range(direction, previous_quotient, now_quotient, repeated) else {
+        #[cfg(feature = "tracing")]
+        if had_progress {
+            bevy_utils::tracing::debug!(
+                runner = ?runner_entity,
+                span = ?span_entity,
+                ?previous_quotient,
+                ?now_quotient,
+                ?direction,
+                "removing time span progress"
+            );
+        }
+        return ProgressUpdate::Remove {
+            span: span_entity,
+            runner: runner_entity,
+            had_progress,
+            state: now_quotient.into(),
+        };
+    };
+
+    let span_max = span.max().duration().as_secs_f32();
+    let span_min = span.min().duration().as_secs_f32();
+
+    let span_length = span_max - span_min;
+
+    let new_now = match use_time {
+        UseTime::Current => runner_elasped_now - span_min,
+        UseTime::Min => 0.,
+        UseTime::Max => span_length,
+    };
+    let new_previous = runner_elasped_previous - span_min;
+
+    let percentage = |secs: f32| {
+        if span_length > 0. {
+            secs / span_length
+        } else {
+            match secs.total_cmp(&0.) {
+                Ordering::Greater => f32::INFINITY,
+                Ordering::Equal => match runner_direction {
+                    Forward => f32::INFINITY,
+                    Backward => f32::NEG_INFINITY,
+                },
+                Ordering::Less => f32::NEG_INFINITY,
+            }
+        }
+    };
+    let new_now_percentage = percentage(new_now);
+    let new_previous_percentage = percentage(new_previous);
+
+    #[cfg(feature = "tracing")]
+    if !had_progress {
+        bevy_utils::tracing::debug!(
+            runner = ?runner_entity,
+            span = ?span_entity,
+            ?previous_quotient,
+            ?now_quotient,
+            ?direction,
+            "inserting time span progress"
+        );
+    }
+
+    // point spans only have infinite percentages
+    let state = if span_length > 0. {
+        TimeSpanState::from_percentage(new_now_percentage)
+    } else {
+        now_quotient.into()
+    };
+
+    if had_progress {
+        ProgressUpdate::Update {
+            span: span_entity,
+            now: new_now,
+            now_percentage: new_now_percentage,
+            state,
+        }
+    } else {
+        ProgressUpdate::Insert {
+            span: span_entity,
+            runner: runner_entity,
+            progress: TimeSpanProgress {
+                now_percentage: new_now_percentage,
+                now: new_now,
+                previous_percentage: new_previous_percentage,
+                previous: new_previous,
+            },
+            state,
+        }
+    }
+}
+
+enum UseTime {
+    Current,
+    Min,
+    Max,
+}
+
+fn span_in_range(
+    direction: TimeDirection,
+    previous_quotient: DurationQuotient,
+    now_quotient: DurationQuotient,
+    repeated: Option<RepeatStyle>,
+) -> Option<UseTime> {
+    use DurationQuotient::*;
+    use RepeatStyle::*;
+    use TimeDirection::*;
+
+    // Look at this behemoth of edge case handling.
+    //
+    // The edge cases are the time when the timer are really short
+    // or delta is really long per frame.
+    //
+    // Currently unknown what happen when timer repeated multiple times in one frame.
+
+    match (
+        direction,
+        previous_quotient,
+        now_quotient,
+        repeated,
+    ) {
+        (_, Inside, Inside, None) => {
+            Some(UseTime::Current)
+        },
+        // ----------------------------------------------------------------
+        | (Forward, Before, Inside, None)
+        | (Forward, Inside, After, None)
+        | (Forward, Before, After, None)
+            => {
+                Some(UseTime::Current)
+            },
+
+        // ----------------------------------------------------------------
+        | (Backward, After, Inside, None)
+        | (Backward, Inside, Before, None)
+        | (Backward, After, Before, None)
+            => {
+                Some(UseTime::Current)
+            },
+
+        // -----------------------------------------------------------------
+        // don't remove these comments, may use for debugging in the future
+        | (Forward, Before, Before, Some(WrapAround)) // 1&2 max
+        | (Forward, Inside, Before, Some(WrapAround)) // 1 max
+            => {
+                // println!("forward wrap use max");
+                Some(UseTime::Max)
+            },
+        | (Forward, Before, Inside, Some(WrapAround)) // 2 now
+        | (Forward, Before, After, Some(WrapAround)) // 2 now, max
+        | (Forward, Inside, Inside, Some(WrapAround)) // 1&2 now
+        | (Forward, Inside, After, Some(WrapAround)) // 2 now, max
+        | (Forward, After, Inside, Some(WrapAround)) // 1 now 
+        | (Forward, After, After, Some(WrapAround)) // 1&2 now, max
+        // | (Forward, After, Before, Some(WrapAround)) // 1
+            => {
+                // println!("forward wrap use current");
+                Some(UseTime::Current)
+            },
+
+        // ----------------------------------------------------------------
+        | (Backward, After, After, Some(WrapAround)) // 1&2 min
+        | (Backward, Inside, After, Some(WrapAround)) // 1 min
+            => {
+                // println!("backward wrap use min");
+                Some(UseTime::Min)
+            },
+        | (Backward, Before, Before, Some(WrapAround)) // 1&2 now, min
+        | (Backward, Before, Inside, Some(WrapAround)) // 1 now 
+        | (Backward, Inside, Before, Some(WrapAround)) // 2 now, min
+        | (Backward, Inside, Inside, Some(WrapAround)) // 1&2 now
+        | (Backward, After, Before, Some(WrapAround)) // 2 now, min
+        | (Backward, After, Inside, Some(WrapAround)) // 2 now
+        // | (Backward, Before, After, Some(WrapAround)) // 1
+            => {
+                // println!("backward wrap use current");
+                Some(UseTime::Current)
+            },
+
+        // ----------------------------------------------------------------
+        | (Backward, Before, Before, Some(PingPong)) // 1&2 now, min
+        | (Backward, Before, Inside, Some(PingPong)) // 1 now
+        | (Backward, Before, After, Some(PingPong)) // 1 now, max
+        | (Backward, Inside, Before, Some(PingPong)) // 2 now, min
+        | (Backward, Inside, Inside, Some(PingPong)) // 1&2 now
+        | (Backward, Inside, After, Some(PingPong)) // 1 now, max
+        | (Backward, After, Before, Some(PingPong)) // 2 now, min
+        | (Backward, After, Inside, Some(PingPong)) // 2 now
+        // | (Backward, After, After, Some(PingPong)) // 1&2
+            => Some(UseTime::Current),
+
+        // ----------------------------------------------------------------
+        // | (Forward, Before, Before, Some(PingPong)) // 1&2
+        | (Forward, Before, Inside, Some(PingPong)) // 2 now
+        | (Forward, Before, After, Some(PingPong)) // 2 now, max
+        | (Forward, Inside, Before, Some(PingPong)) // 1 now, min
+        | (Forward, Inside, Inside, Some(PingPong)) // 1&2 now
+        | (Forward, Inside, After, Some(PingPong)) // 2 now, max
+        | (Forward, After, Before, Some(PingPong)) // 1 now, min
+        | (Forward, After, Inside, Some(PingPong)) // 1 now
+        | (Forward, After, After, Some(PingPong)) // 1&2 now, max
+            => Some(UseTime::Current),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use bevy::tasks::{ComputeTaskPool, TaskPool};
+    use bevy_ecs::system::{RunSystemOnce as _, SystemId};
+
+    use super::*;
+
+    fn secs(secs: f32) -> Duration {
+        Duration::from_secs_f32(secs)
+    }
+
+    fn test_world() -> World {
+        // `time_runner_system` iterates runners in parallel
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::default();
+        world.init_resource::<Events<TimeSpanEntered>>();
+        world.init_resource::<Events<TimeSpanExited>>();
+        world.init_resource::<Events<TimeRunnerStarted>>();
+        world.init_resource::<Events<TimeRunnerPaused>>();
+        world.init_resource::<Events<TimeRunnerResumed>>();
+        world.init_resource::<Events<TimeRunnerLooped>>();
+        world.init_resource::<Events<TimeRunnerEnded>>();
+        world
+    }
+
+    /// Run each of `systems` once in order then apply their commands.
+    pub(crate) fn run_systems(world: &mut World, systems: &[SystemId]) {
+        for system in systems {
+            world.run_system(*system).unwrap();
+        }
+        world.flush();
+    }
+
+    // fn eq(lhs: f32, rhs: f32) -> bool {
+    //     (lhs - rhs).abs() <= f32::EPSILON
+    // }
+
+    #[test]
+    fn timer() {
+        for high_precision in [false, true] {
+            let mut timer = TimeRunner::new(secs(5.));
+            timer.set_high_precision(high_precision);
+
+            timer.raw_tick(2.5);
+            assert_eq!(timer.elasped.now, 2.5);
+            assert_eq!(timer.elasped.now_period, 0.5);
+
+            timer.raw_tick(2.5);
+            assert_eq!(timer.elasped.now, 5.);
+            assert_eq!(timer.elasped.now_period, 1.);
+
+            timer.raw_tick(1.);
+            assert_eq!(timer.elasped.now, 5.);
+            assert_eq!(timer.elasped.now_period, 1.);
+
+            timer.set_tick(0.);
+
+            timer.raw_tick(3.);
+            assert_eq!(timer.elasped.now, 3.);
+            assert_eq!(timer.elasped.now_period, 3. / 5.);
+
+            timer.raw_tick(3.);
+            assert_eq!(timer.elasped.now, 5.);
+            assert_eq!(timer.elasped.now_period, 1.);
+
+            timer.raw_tick(1.);
+            assert_eq!(timer.elasped.now, 5.);
+            assert_eq!(timer.elasped.now_period, 1.);
+        }
+    }
+
+    #[test]
+    fn timer_backward() {
+        let mut timer = TimeRunner::new(secs(5.));
+        timer.set_direction(TimeDirection::Backward);
+
+        timer.raw_tick(1.);
+        assert_eq!(timer.elasped.now, 0.);
+        assert_eq!(timer.elasped.now_period, 0.);
+
+        timer.set_tick(5.);
+
+        timer.raw_tick(2.5);
+        assert_eq!(timer.elasped.now, 2.5);
+        assert_eq!(timer.elasped.now_period, 0.5);
+
+        timer.raw_tick(1.);
+        assert_eq!(timer.elasped.now, 1.5);
+        assert_eq!(timer.elasped.now_period, 1.5 / 5.);
+
+        timer.raw_tick(2.);
+        assert_eq!(timer.elasped.now, 0.);
+        assert_eq!(timer.elasped.now_period, 0.);
+    }
+
+    #[test]
+    fn elasped_delta() {
+        let mut timer = TimeRunner::new(secs(4.));
+        timer.tick(1.);
+        timer.tick(2.);
+        assert_eq!(timer.elasped().delta(), 2.);
+        assert_eq!(timer.elasped().delta_period(), 0.5);
+        assert_eq!(timer.elasped().now_as_duration(), secs(3.));
+        assert_eq!(timer.elasped().previous_as_duration(), secs(1.));
+
+        timer.set_direction(TimeDirection::Backward);
+        timer.tick(1.);
+        assert_eq!(timer.elasped().delta(), -1.);
+        assert_eq!(timer.elasped().delta_period(), -0.25);
+
+        timer.set_tick(-1.);
+        assert_eq!(timer.elasped().now_as_duration(), Duration::ZERO);
+        timer.collaspe_elasped();
+        assert_eq!(timer.elasped().previous_as_duration(), Duration::ZERO);
+    }
+
+    #[test]
+    fn timer_high_precision() {
+        let mut low = TimeRunner::new(secs(3600.));
+        let mut high = TimeRunner::new(secs(3600.)).with_high_precision();
+        assert!(high.is_high_precision());
+        for _ in 0..144 * 60 * 30 {
+            low.tick(1. / 144.);
+            high.tick(1. / 144.);
+        }
+        let expected = 1800.;
+        assert!((high.elasped_f64().now() - expected).abs() < 1e-3);
+        assert!((high.elasped().now() - expected as f32).abs() < 1e-3);
+        assert!((low.elasped().now() - expected as f32).abs() > 1e-3);
+
+        // setting the time directly falls back to the `f32` elasped time
+        high.set_tick(2.);
+        assert_eq!(high.elasped_f64().now(), 2.);
+        high.set_high_precision(false);
+        assert!(!high.is_high_precision());
+    }
+
+    #[test]
+    fn timer_wrap_around() {
+        for high_precision in [false, true] {
+            let mut timer = TimeRunner::new(secs(5.));
+            timer.set_high_precision(high_precision);
+            timer.set_repeat(Some((Repeat::Infinitely, RepeatStyle::WrapAround)));
+
+            timer.raw_tick(1.);
+            assert_eq!(timer.elasped.now, 1.);
+            assert_eq!(timer.elasped.now_period, 1. / 5.);
+
+            timer.raw_tick(2.5);
+            assert_eq!(timer.elasped.now, 3.5);
+            assert_eq!(timer.elasped.now_period, 3.5 / 5.);
+
+            timer.raw_tick(1.);
+            assert_eq!(timer.elasped.now, 4.5);
+            assert_eq!(timer.elasped.now_period, 4.5 / 5.);
+
+            timer.raw_tick(1.);
+            assert_eq!(timer.elasped.now, 0.5);
+            assert_eq!(timer.elasped.now_period, 5.5 / 5.);
+
+            timer.raw_tick(1.);
+            assert_eq!(timer.elasped.now, 1.5);
+            assert_eq!(timer.elasped.now_period, 1.5 / 5.);
+
+            timer.raw_tick(3.5);
+            assert_eq!(timer.elasped.now, 0.);
+            assert_eq!(timer.elasped.now_period, 5. / 5.);
+
+            timer.raw_tick(1.);
+            assert_eq!(timer.elasped.now, 1.);
+            assert_eq!(timer.elasped.now_period, 1. / 5.);
+        }
+    }
+
+    #[test]
+    fn timer_backward_wrap_around() {
+        for high_precision in [false, true] {
+            let mut timer = TimeRunner::new(secs(5.));
+            timer.set_high_precision(high_precision);
+            timer.set_repeat(Some((Repeat::Infinitely, RepeatStyle::WrapAround)));
+            timer.set_direction(TimeDirection::Backward);
+
+            timer.raw_tick(1.);
+            assert_eq!(timer.elasped.now, 4.);
+            assert_eq!(timer.elasped.now_period, -1. / 5.);
+
+            timer.raw_tick(2.5);
+            assert_eq!(timer.elasped.now, 1.5);
+            assert_eq!(timer.elasped.now_period, 1.5 / 5.);
+
+            timer.raw_tick(1.);
+            assert_eq!(timer.elasped.now, 0.5);
+            assert_eq!(timer.elasped.now_period, 0.5 / 5.);
+
+            timer.raw_tick(1.);
+            assert_eq!(timer.elasped.now, 4.5);
+            assert_eq!(timer.elasped.now_period, -0.5 / 5.);
+        }
+    }
+
+    #[test]
+    fn timer_jump_back() {
+        for high_precision in [false, true] {
+            let mut timer = TimeRunner::new(secs(5.));
+            timer.set_high_precision(high_precision);
+            timer.set_repeat(Some((Repeat::Infinitely, RepeatStyle::JumpBack(secs(2.)))));
+
+            timer.raw_tick(4.5);
+            assert_eq!(timer.elasped.now, 4.5);
+            assert_eq!(timer.elasped.now_period, 4.5 / 5.);
+
+            timer.raw_tick(1.);
+            assert_eq!(timer.elasped.now, 2.5);
+            assert_eq!(timer.elasped.now_period, 5.5 / 5.);
+
+            timer.raw_tick(2.);
+            assert_eq!(timer.elasped.now, 4.5);
+            assert_eq!(timer.elasped.now_period, 4.5 / 5.);
+
+            timer.raw_tick(4.);
+            assert_eq!(timer.elasped.now, 2.5);
+            assert_eq!(timer.elasped.now_period, 8.5 / 5.);
+
+            timer.set_direction(TimeDirection::Backward);
+            timer.raw_tick(3.);
+            assert_eq!(timer.elasped.now, 2.5);
+            assert_eq!(timer.elasped.now_period, -0.5 / 5.);
+        }
+    }
+
+    #[test]
+    fn timer_wrap_around_with_offset() {
+        assert_eq!(RepeatStyle::wrap_around_with_offset(1.), None);
+        assert_eq!(RepeatStyle::wrap_around_with_offset(-0.1), None);
+        assert_eq!(RepeatStyle::wrap_around_with_offset(f32::NAN), None);
+        assert!(WrapOffset::try_from(f32::NAN).is_err());
+        assert_eq!(WrapOffset::new(-0.), WrapOffset::new(0.));
+
+        let style = RepeatStyle::wrap_around_with_offset(0.25).unwrap();
+        let mut timer = TimeRunner::new(secs(4.));
+        timer.set_repeat(Some((Repeat::Infinitely, style)));
+
+        timer.raw_tick(3.5);
+        assert_eq!(timer.elasped.now, 3.5);
+
+        timer.raw_tick(1.);
+        assert_eq!(timer.elasped.now, 1.5);
+        assert_eq!(timer.elasped.now_period, 4.5 / 4.);
+
+        timer.set_direction(TimeDirection::Backward);
+        timer.raw_tick(2.);
+        assert_eq!(timer.elasped.now, 2.5);
+        assert_eq!(timer.elasped.now_period, -0.5 / 4.);
+    }
+
+    #[test]
+    fn jump_back_skips_spans() {
+        let mut world = test_world();
+
+        let mut time_runner = TimeRunner::new(secs(5.));
+        time_runner.set_repeat(Some((Repeat::Infinitely, RepeatStyle::JumpBack(secs(2.)))));
+        let mut before_id = Entity::PLACEHOLDER;
+        let mut after_id = Entity::PLACEHOLDER;
+        let runner = world
+            .spawn(time_runner)
+            .with_children(|c| {
+                before_id = c
+                    .spawn(TimeSpan::try_from(secs(0.)..secs(2.)).unwrap())
+                    .id();
+                after_id = c
+                    .spawn(TimeSpan::try_from(secs(2.)..secs(3.)).unwrap())
+                    .id();
+            })
+            .id();
+        let tick = |world: &mut World, secs: f32| {
+            world.get_mut::<TimeRunner>(runner).unwrap().tick(secs);
+            world.run_system_once(time_runner_system).unwrap();
+        };
+
+        tick(&mut world, 1.);
+        assert!(world.get::<TimeSpanProgress>(before_id).is_some());
+        tick(&mut world, 3.5);
+        tick(&mut world, 1.);
+        assert_eq!(
+            world.get::<TimeRunner>(runner).unwrap().elasped().now(),
+            2.5
+        );
+        assert!(world.get::<TimeSpanProgress>(before_id).is_none());
+        assert_eq!(
+            world.get::<TimeSpanProgress>(after_id).map(|p| p.now),
+            Some(0.5)
+        );
+    }
+
+    #[test]
+    fn timer_wrap_around_times() {
+        let mut timer = TimeRunner::new(secs(5.));
+        timer.set_repeat(Some((Repeat::times(2), RepeatStyle::WrapAround)));
+
+        timer.raw_tick(4.);
+        assert_eq!(timer.elasped.now, 4.);
+        assert_eq!(timer.elasped.now_period, 4. / 5.);
+        assert_eq!(
+            timer.repeat.unwrap().0,
+            Repeat::Times {
+                times: 2,
+                times_repeated: 0
+            },
+        );
+
+        timer.raw_tick(4.);
+        assert_eq!(timer.elasped.now, 3.);
+        assert_eq!(timer.elasped.now_period, 8. / 5.);
+        assert_eq!(
+            timer.repeat.unwrap().0,
+            Repeat::Times {
+                times: 2,
+                times_repeated: 1
+            },
+        );
+
+        timer.raw_tick(4.);
+        assert_eq!(timer.elasped.now, 2.);
+        assert_eq!(timer.elasped.now_period, 7. / 5.);
+        assert_eq!(
+            timer.repeat.unwrap().0,
+            Repeat::Times {
+                times: 2,
+                times_repeated: 2
+            },
+        );
+
+        timer.raw_tick(4.);
+        assert_eq!(timer.elasped.now, 5.);
+        assert_eq!(timer.elasped.now_period, 1.);
+        assert_eq!(
+            timer.repeat.unwrap().0,
+            Repeat::Times {
+                times: 2,
+                times_repeated: 2
+            },
+        );
+
+        timer.raw_tick(1.);
+        assert_eq!(timer.elasped.now, 5.);
+        assert_eq!(timer.elasped.now_period, 1.);
+        assert_eq!(
+            timer.repeat.unwrap().0,
+            Repeat::Times {
+                times: 2,
+                times_repeated: 2
+            },
+        );
+    }
+
+    #[test]
+    fn timer_backward_wrap_around_times() {
+        let mut timer = TimeRunner::new(secs(5.));
+        timer.set_repeat(Some((Repeat::times(2), RepeatStyle::WrapAround)));
+        timer.set_direction(TimeDirection::Backward);
+
+        timer.raw_tick(4.);
+        assert_eq!(timer.elasped.now, 1.);
+        assert_eq!(timer.elasped.now_period, -4. / 5.);
+        assert_eq!(
+            timer.repeat.unwrap().0,
+            Repeat::Times {
+                times: 2,
+                times_repeated: 1
+            },
+        );
+
+        timer.raw_tick(4.);
+        assert_eq!(timer.elasped.now, 2.);
+        assert_eq!(timer.elasped.now_period, -3. / 5.);
+        assert_eq!(
+            timer.repeat.unwrap().0,
+            Repeat::Times {
+                times: 2,
+                times_repeated: 2
+            },
+        );
+
+        timer.raw_tick(4.);
+        assert_eq!(timer.elasped.now, 0.);
+        assert_eq!(timer.elasped.now_period, 0. / 5.);
+        assert_eq!(
+            timer.repeat.unwrap().0,
+            Repeat::Times {
+                times: 2,
+                times_repeated: 2
+            },
+        );
+    }
+
+    #[test]
+    fn timer_ping_pong() {
+        for high_precision in [false, true] {
+            let mut timer = TimeRunner::new(secs(5.));
+            timer.set_high_precision(high_precision);
+            timer.set_repeat(Some((Repeat::Infinitely, RepeatStyle::PingPong)));
+
+            timer.raw_tick(3.);
+            assert_eq!(timer.elasped.now, 3.);
+            assert_eq!(timer.elasped.now_period, 3. / 5.);
+            assert_eq!(timer.direction, TimeDirection::Forward);
+
+            timer.raw_tick(3.);
+            assert_eq!(timer.elasped.now, 4.);
+            assert_eq!(timer.elasped.now_period, 6. / 5.);
+            assert_eq!(timer.direction, TimeDirection::Backward);
+
+            timer.raw_tick(3.);
+            assert_eq!(timer.elasped.now, 1.);
+            assert_eq!(timer.elasped.now_period, 1. / 5.);
+            assert_eq!(timer.direction, TimeDirection::Backward);
+
+            timer.raw_tick(3.);
+            assert_eq!(timer.elasped.now, 2.);
+            assert_eq!(timer.elasped.now_period, -2. / 5.);
+            assert_eq!(timer.direction, TimeDirection::Forward);
+
+            timer.raw_tick(3.);
+            assert_eq!(timer.elasped.now, 5.);
+            assert_eq!(timer.elasped.now_period, 5. / 5.);
+            assert_eq!(timer.direction, TimeDirection::Backward);
+
+            timer.raw_tick(3.);
+            assert_eq!(timer.elasped.now, 2.);
+            assert_eq!(timer.elasped.now_period, 2. / 5.);
+            assert_eq!(timer.direction, TimeDirection::Backward);
+        }
+    }
+
+    #[test]
+    fn repeat_counts() {
+        let mut repeat = Repeat::times(3);
+        repeat.advance_counter_by(1);
+        assert_eq!(repeat.times_remaining(), Some(2));
+        assert_eq!(repeat.times_completed(), 1);
+        assert_eq!(repeat.total_iterations(), Some(4));
+        assert_eq!(Repeat::times(0).total_iterations(), Some(1));
+
+        let mut repeat = Repeat::infinitely_counted();
+        repeat.advance_counter_by(4);
+        assert_eq!(repeat.times_remaining(), None);
+        assert_eq!(repeat.times_completed(), 4);
+        assert_eq!(repeat.total_iterations(), None);
+
+        let mut repeat = Repeat::infinitely();
+        repeat.advance_counter_by(4);
+        assert_eq!(repeat.times_remaining(), None);
+        assert_eq!(repeat.times_completed(), 0);
+        assert_eq!(repeat.total_iterations(), None);
+    }
+
+    #[test]
+    fn timer_will_complete_in() {
+        let mut timer = TimeRunner::new(secs(2.));
+        timer.tick(0.5);
+        assert_eq!(timer.will_complete_in(), Some(secs(1.5)));
+
+        let mut timer = TimeRunner::new(secs(2.)).with_repeat_times(2);
+        timer.tick(0.5);
+        assert_eq!(timer.will_complete_in(), Some(secs(5.5)));
+        timer.tick(2.);
+        assert_eq!(timer.will_complete_in(), Some(secs(3.5)));
+        timer.tick(1.75);
+        assert_eq!(timer.will_complete_in(), Some(secs(1.75)));
+        timer.tick(1.75);
+        assert_eq!(timer.will_complete_in(), Some(Duration::ZERO));
+
+        let timer = TimeRunner::new(secs(2.))
+            .with_repeat(Some((Repeat::infinitely(), RepeatStyle::WrapAround)));
+        assert_eq!(timer.will_complete_in(), None);
+    }
+
+    #[test]
+    fn timer_total_duration() {
+        let timer = TimeRunner::new(secs(2.));
+        assert_eq!(timer.total_duration(), Some(secs(2.)));
+        assert_eq!(
+            timer.clone().with_repeat_times(0).total_duration(),
+            Some(secs(2.))
+        );
+        assert_eq!(
+            timer.clone().with_repeat_times(3).total_duration(),
+            Some(secs(8.))
+        );
+        for repeat in [Repeat::infinitely(), Repeat::infinitely_counted()] {
+            let timer = timer
+                .clone()
+                .with_repeat(Some((repeat, RepeatStyle::PingPong)));
+            assert_eq!(timer.total_duration(), None);
+        }
+    }
+
+    #[test]
+    fn timer_completion_ratio() {
+        let mut timer = TimeRunner::new(secs(10.)).with_repeat_times(3);
+        timer.tick(10.5);
+        assert_eq!(timer.completion_ratio(), 10.5 / 40.);
+
+        let mut timer =
+            TimeRunner::new(secs(2.)).with_repeat(Some((Repeat::times(1), RepeatStyle::PingPong)));
+        timer.tick(1.);
+        assert_eq!(timer.completion_ratio(), 0.25);
+        timer.tick(2.);
+        assert_eq!(timer.completion_ratio(), 0.75);
+        timer.tick(1.);
+        timer.tick(0.);
+        assert!(timer.is_completed());
+        assert_eq!(timer.completion_ratio(), 1.);
+
+        assert_eq!(TimeRunner::new(Duration::ZERO).completion_ratio(), 1.);
+        let timer = TimeRunner::new(secs(2.))
+            .with_repeat(Some((Repeat::infinitely(), RepeatStyle::WrapAround)));
+        assert!(timer.completion_ratio().is_nan());
+    }
+
+    #[test]
+    fn timer_elapsed_total_secs() {
+        let mut timer = TimeRunner::new(secs(5.)).with_repeat_times(3);
+        timer.tick(2.);
+        assert_eq!(timer.elapsed_total_secs(), 2.);
+        timer.tick(14.);
+        assert_eq!(timer.elapsed_total_secs(), 16.);
+
+        let mut timer = TimeRunner::new(secs(5.))
+            .with_repeat(Some((Repeat::infinitely(), RepeatStyle::WrapAround)));
+        timer.tick(7.);
+        assert_eq!(timer.elapsed_total_secs(), 2.);
+
+        let mut timer =
+            TimeRunner::new(secs(4.)).with_repeat(Some((Repeat::times(1), RepeatStyle::PingPong)));
+        let mut previous = 0.;
+        for _ in 0..8 {
+            timer.tick(1.);
+            assert!(timer.elapsed_total_secs() > previous);
+            previous = timer.elapsed_total_secs();
+        }
+        assert_eq!(previous, 8.);
+
+        let mut timer = TimeRunner::new(secs(4.)).with_direction(TimeDirection::Backward);
+        timer.reset();
+        timer.tick(1.);
+        assert_eq!(timer.elapsed_total_secs(), 1.);
+    }
+
+    #[test]
+    fn timer_ping_pong_once() {
+        let mut timer = TimeRunner::new(secs(2.))
+            .with_direction(TimeDirection::Backward)
+            .ping_pong_once();
+        assert_eq!(timer.direction(), TimeDirection::Forward);
+
+        timer.tick(1.5);
+        timer.tick(1.);
+        assert_eq!(timer.direction(), TimeDirection::Backward);
+        assert_eq!(timer.elasped().now(), 1.5);
+        timer.tick(1.);
+        timer.tick(1.);
+        assert_eq!(timer.elasped().now(), 0.);
+        timer.tick(1.);
+        assert!(timer.is_completed());
+        assert_eq!(timer.direction(), TimeDirection::Backward);
+        assert_eq!(timer.elasped().now(), 0.);
+    }
+
+    #[test]
+    fn timer_tick_duration() {
+        let mut timer = TimeRunner::new(secs(5.)).with_time_scale(2.);
+        timer.tick_duration(secs(1.));
+        assert_eq!(timer.elasped().now(), 2.);
+        timer.raw_tick_duration(secs(1.));
+        assert_eq!(timer.elasped().now(), 3.);
+        timer.set_paused(true);
+        timer.tick_duration(secs(1.));
+        assert_eq!(timer.elasped().now(), 3.);
+    }
+
+    #[test]
+    fn timer_elapsed_duration() {
+        let mut timer = TimeRunner::new(secs(5.));
+        timer.set_elapsed_duration(secs(1.5));
+        assert_eq!(timer.elasped().now(), 1.5);
+        assert_eq!(timer.elasped().now_period(), 1.5 / 5.);
+        assert_eq!(timer.elapsed_duration(), secs(1.5));
+    }
+
+    #[test]
+    fn repeat_with_style() {
+        for repeat in [
+            Repeat::infinitely(),
+            Repeat::infinitely_counted(),
+            Repeat::times(3),
+        ] {
+            for style in [RepeatStyle::WrapAround, RepeatStyle::PingPong] {
+                assert_eq!(repeat.with_style(style), (repeat, style));
+            }
+        }
+    }
+
+    #[test]
+    fn repeat_reset_counter() {
+        let mut repeat = Repeat::times(3);
+        repeat.advance_counter_by(3);
+        assert!(repeat.exhausted());
+        repeat.reset_counter();
+        assert_eq!(repeat, Repeat::times(3));
+        assert_eq!(repeat.advance_counter_by(2), 2);
+        assert_eq!(repeat.times_completed(), 2);
+
+        let mut repeat = Repeat::infinitely_counted();
+        repeat.advance_counter_by(5);
+        repeat.reset_counter();
+        assert_eq!(repeat.advance_counter_by(1), 1);
+        assert_eq!(repeat.times_completed(), 1);
+
+        let mut repeat = Repeat::infinitely();
+        repeat.reset_counter();
+        assert_eq!(repeat, Repeat::Infinitely);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn timer_serde_round_trip() {
+        let mut timer = TimeRunner::new(secs(5.))
+            .with_direction(TimeDirection::Backward)
+            .with_time_scale(1.5)
+            .with_repeat(Some((Repeat::times(3), RepeatStyle::PingPong)));
+        timer.tick(7.);
+
+        let json = serde_json::to_string(&timer).unwrap();
+        assert!(json.contains(r#""length":5.0"#), "{json}");
+        let deserialized: TimeRunner = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, timer);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn wrap_offset_serde() {
+        let style = RepeatStyle::wrap_around_with_offset(0.25).unwrap();
+        let json = serde_json::to_string(&style).unwrap();
+        assert_eq!(json, r#"{"WrapAroundWithOffset":0.25}"#);
+        assert_eq!(serde_json::from_str::<RepeatStyle>(&json).unwrap(), style);
+        assert!(serde_json::from_str::<RepeatStyle>(r#"{"WrapAroundWithOffset":1.5}"#).is_err());
+    }
+
+    #[test]
+    fn timer_from_secs() {
+        assert_eq!(TimeRunner::from_secs(1.5), TimeRunner::new(secs(1.5)));
+        assert_eq!(TimeRunner::from_secs_f64(1.5), TimeRunner::new(secs(1.5)));
+        assert_eq!(TimeRunner::from_millis(1500), TimeRunner::new(secs(1.5)));
+    }
+
+    #[test]
+    fn timer_seek_normalized() {
+        let mut timer = TimeRunner::new(secs(4.));
+        timer.seek_normalized(0.25);
+        assert_eq!(timer.elasped.now, 1.);
+        assert_eq!(timer.elasped.previous, 1.);
+
+        let mut expected = timer.clone();
+        expected.seek_to(timer.length());
+        timer.seek_normalized(1.);
+        assert_eq!(timer, expected);
+        timer.seek_normalized(2.);
+        assert_eq!(timer, expected);
+
+        timer.seek_normalized(-1.);
+        assert_eq!(timer.elasped.now, 0.);
+    }
+
+    #[test]
+    fn timer_reverse() {
+        let mut world = test_world();
+        let runner = world.spawn(TimeRunner::new(secs(10.))).id();
+        let tick = world.register_system(|mut t: TickTimeRunners<()>| t.tick(1.));
+        for _ in 0..3 {
+            world.run_system(tick).unwrap();
+        }
+
+        world.get_mut::<TimeRunner>(runner).unwrap().reverse();
+        let timer = world.get::<TimeRunner>(runner).unwrap();
+        assert_eq!(timer.direction, TimeDirection::Backward);
+        assert_eq!(timer.elasped.now, 7.);
+        assert_eq!(timer.elasped.previous, 7.);
+
+        world.run_system(tick).unwrap();
+        let timer = world.get::<TimeRunner>(runner).unwrap();
+        assert_eq!(timer.elasped.now, 6.);
+        assert_eq!(timer.elasped.previous, 7.);
+    }
+
+    #[test]
+    fn timer_snapshot() {
+        let mut timer = TimeRunner::new(secs(5.))
+            .with_time_scale(2.)
+            .with_repeat(Some((Repeat::times(2), RepeatStyle::PingPong)));
+        timer.tick(3.);
+        let snap = timer.save_snapshot();
+
+        let mut restored = TimeRunner::new(secs(5.));
+        restored.restore_snapshot(&snap);
+        assert_eq!(restored.elasped.now, 4.);
+        assert_eq!(restored.elasped.previous, 4.);
+        assert_eq!(restored.direction, TimeDirection::Backward);
+        assert_eq!(restored.time_scale, 2.);
+        assert_eq!(restored.repeat, timer.repeat);
+
+        timer.tick(1.);
+        restored.tick(1.);
+        assert_eq!(restored.elasped.now, timer.elasped.now);
+        assert_eq!(
+            restored.save_snapshot().repeat,
+            timer.save_snapshot().repeat
+        );
+    }
+
+    #[test]
+    fn timer_builder() {
+        let timer = TimeRunner::new(secs(5.))
+            .with_length(secs(10.))
+            .with_paused(true)
+            .with_time_scale(2.)
+            .with_direction(TimeDirection::Backward)
+            .with_repeat(Some((Repeat::infinitely(), RepeatStyle::PingPong)));
+
+        let mut expected = TimeRunner::new(secs(10.));
+        expected
+            .set_paused(true)
+            .set_time_scale(2.)
+            .set_direction(TimeDirection::Backward)
+            .set_repeat(Some((Repeat::infinitely(), RepeatStyle::PingPong)));
+        assert_eq!(timer, expected);
+    }
+
+    #[test]
+    fn timer_elapsed_percentage() {
+        let mut timer = TimeRunner::new(secs(4.));
+        assert_eq!(timer.elapsed_percentage(), 0.);
+
+        timer.tick(1.);
+        assert_eq!(timer.elapsed_percentage(), 0.25);
+
+        timer.tick(10.);
+        assert_eq!(timer.elapsed_percentage(), 1.);
+
+        timer.set_tick(-1.);
+        assert_eq!(timer.elapsed_percentage(), 0.);
+
+        timer.set_repeat(Some((Repeat::Infinitely, RepeatStyle::WrapAround)));
+        timer.set_tick(0.);
+        timer.tick(5.);
+        assert_eq!(timer.elapsed_percentage(), 0.25);
+    }
+
+    #[test]
+    fn timer_remaining() {
+        let mut timer = TimeRunner::new(secs(5.));
+        assert_eq!(timer.remaining(), secs(5.));
+
+        timer.tick(2.);
+        assert_eq!(timer.remaining(), secs(3.));
+
+        timer.set_direction(TimeDirection::Backward);
+        assert_eq!(timer.remaining(), secs(2.));
+
+        timer.tick(2.);
+        timer.tick(2.);
+        assert!(timer.is_completed());
+        assert_eq!(timer.remaining(), Duration::ZERO);
+
+        let mut timer = TimeRunner::new(secs(5.));
+        timer.set_tick(-1.);
+        assert_eq!(timer.remaining(), secs(5.));
+        timer.set_direction(TimeDirection::Backward);
+        timer.set_tick(-1.);
+        assert_eq!(timer.remaining(), Duration::ZERO);
+    }
+
+    #[test]
+    fn timer_reset() {
+        let mut timer = TimeRunner::new(secs(5.));
+        timer.set_repeat(Some((Repeat::times(2), RepeatStyle::WrapAround)));
+        timer.set_time_scale(2.);
+
+        timer.tick(3.);
+        timer.reset();
+        assert_eq!(timer.elasped, TimeRunnerElasped::default());
+        assert_eq!(timer.repeat.unwrap().0, Repeat::times(2));
+        assert_eq!(timer.time_scale, 2.);
+
+        timer.tick(3.);
+        timer.reset_keep_repeat();
+        assert_eq!(timer.elasped, TimeRunnerElasped::default());
+        assert_eq!(
+            timer.repeat.unwrap().0,
+            Repeat::Times {
+                times: 2,
+                times_repeated: 1
+            },
+        );
+
+        timer.set_direction(TimeDirection::Backward);
+        timer.reset();
+        assert_eq!(timer.elasped.now, 5.);
+        assert_eq!(timer.elasped.previous, 5.);
+        assert_eq!(timer.elasped.now_period, 1.);
+        assert_eq!(timer.elasped.previous_period, 1.);
+    }
+
+    #[test]
+    fn timer_pause_and_reset() {
+        let mut timer = TimeRunner::new(secs(5.)).with_repeat_times(2);
+        timer.tick(5.);
+        timer.tick(1.);
+        timer.pause_and_reset_keep_repeat();
+        assert!(timer.paused());
+        assert!(!timer.is_completed());
+        assert_eq!(timer.elasped, TimeRunnerElasped::default());
+        assert_eq!(timer.repeat.unwrap().0.times_completed(), 1);
+
+        timer.set_paused(false);
+        timer.tick(5.);
+        timer.tick(5.);
+        assert!(timer.is_completed());
+        timer.pause_and_reset();
+        assert!(timer.paused());
+        assert!(!timer.is_completed());
+        assert_eq!(timer.elasped, TimeRunnerElasped::default());
+        assert_eq!(timer.repeat.unwrap().0, Repeat::times(2));
+    }
+
+    #[test]
+    fn timer_resize() {
+        let mut timer = TimeRunner::new(secs(4.));
+        timer.tick(1.);
+        timer.tick(1.);
+        timer.resize(secs(8.));
+        assert_eq!(timer.length(), secs(8.));
+        assert_eq!((timer.elasped.previous, timer.elasped.now), (2., 4.));
+        assert_eq!(timer.elasped.now_period, 0.5);
+
+        timer.resize_preserving_elapsed_absolute(secs(3.));
+        assert_eq!((timer.elasped.previous, timer.elasped.now), (2., 3.));
+        assert_eq!(timer.elasped.now_period, 1.);
+        timer.resize_preserving_elapsed_absolute(secs(6.));
+        assert_eq!((timer.elasped.previous, timer.elasped.now), (2., 3.));
+        assert_eq!(timer.elasped.now_period, 0.5);
+    }
+
+    #[test]
+    fn timer_seek_to() {
+        let mut world = test_world();
+
+        let mut time_runner = TimeRunner::new(secs(10.));
+        time_runner.tick(1.);
+        let mut spans = [Entity::PLACEHOLDER; 3];
+        let runner = world
+            .spawn(time_runner)
+            .with_children(|c| {
+                spans[0] = c
+                    .spawn(TimeSpan::try_from(secs(0.)..secs(3.)).unwrap())
+                    .id();
+                spans[1] = c
+                    .spawn(TimeSpan::try_from(secs(3.)..secs(7.)).unwrap())
+                    .id();
+                spans[2] = c
+                    .spawn(TimeSpan::try_from(secs(7.)..=secs(10.)).unwrap())
+                    .id();
+            })
+            .id();
+        world.run_system_once(time_runner_system).unwrap();
+
+        world
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .seek_to(secs(8.));
+        world.run_system_once(time_runner_system).unwrap();
+
+        assert!(world.get::<TimeSpanProgress>(spans[0]).is_none());
+        assert!(world.get::<TimeSpanProgress>(spans[1]).is_none());
+        let progress = world.get::<TimeSpanProgress>(spans[2]).unwrap();
+        assert_eq!(progress.previous, progress.now);
+
+        world
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .seek_to(secs(20.));
+        assert_eq!(world.get::<TimeRunner>(runner).unwrap().elasped().now, 10.);
+    }
+
+    #[test]
+    fn global_time_scale() {
+        let mut world = test_world();
+        world.insert_resource(GlobalTimeScale(0.5));
+        let runner = world
+            .spawn(TimeRunner::new(secs(1.)).with_time_scale(2.))
+            .id();
+        let ignored = world
+            .spawn((TimeRunner::new(secs(1.)), IgnoreGlobalTimeScale))
+            .id();
+        let system = world.register_system(|mut tick_time_runners: TickTimeRunners<()>| {
+            tick_time_runners.tick(0.25);
+        });
+        world.run_system(system).unwrap();
+
+        let now = |entity: Entity| world.get::<TimeRunner>(entity).unwrap().elasped().now;
+        assert_eq!(now(runner), 0.25);
+        assert_eq!(now(ignored), 0.25);
+        assert_eq!(world.resource::<GlobalTimeScale>().0, 0.5);
+    }
+
+    #[test]
+    fn global_time_pause() {
+        let mut world = test_world();
+        world.insert_resource(GlobalTimePause(true));
+        let runner = world.spawn(TimeRunner::new(secs(1.))).id();
+        let ignored = world
+            .spawn((TimeRunner::new(secs(1.)), IgnoreGlobalTimePause))
+            .id();
+        let system = world.register_system(|mut tick_time_runners: TickTimeRunners<()>| {
+            tick_time_runners.tick(0.25);
+        });
+        let now =
+            |world: &World, entity: Entity| world.get::<TimeRunner>(entity).unwrap().elasped().now;
+
+        world.run_system(system).unwrap();
+        assert_eq!(now(&world, runner), 0.);
+        assert_eq!(now(&world, ignored), 0.25);
+
+        world.insert_resource(GlobalTimePause(false));
+        world.run_system(system).unwrap();
+        assert_eq!(now(&world, runner), 0.25);
+        assert_eq!(now(&world, ignored), 0.5);
+        assert!(!world.get::<TimeRunner>(runner).unwrap().paused());
+    }
+
+    #[test]
+    fn start_delay() {
+        let mut world = test_world();
+        let runner = world
+            .spawn((TimeRunner::new(secs(1.)), StartDelay(secs(0.375))))
+            .id();
+        let system = world.register_system(|mut tick_time_runners: TickTimeRunners<()>| {
+            tick_time_runners.tick(0.25);
+        });
+        let now = |world: &World| world.get::<TimeRunner>(runner).unwrap().elasped().now;
+
+        world.run_system(system).unwrap();
+        assert_eq!(now(&world), 0.);
+        assert_eq!(
+            world.get::<StartDelay>(runner),
+            Some(&StartDelay(secs(0.125)))
+        );
+
+        world
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(true);
+        world.run_system(system).unwrap();
+        assert_eq!(
+            world.get::<StartDelay>(runner),
+            Some(&StartDelay(secs(0.125)))
+        );
+
+        world
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(false);
+        world.run_system(system).unwrap();
+        assert_eq!(now(&world), 0.125);
+        assert_eq!(world.get::<StartDelay>(runner), None);
+        assert_eq!(world.resource::<Events<TimeRunnerStarted>>().len(), 1);
+    }
+
+    #[test]
+    fn start_delay_negative_delta() {
+        let mut world = test_world();
+        let runner = world
+            .spawn((TimeRunner::new(secs(1.)), StartDelay(secs(0.375))))
+            .id();
+        world
+            .run_system_once(|mut tick_time_runners: TickTimeRunners<()>| {
+                tick_time_runners.tick(-0.25);
+            })
+            .unwrap();
+        assert_eq!(
+            world.get::<StartDelay>(runner),
+            Some(&StartDelay(secs(0.375)))
+        );
+    }
+
+    #[test]
+    fn time_runner_started_again() {
+        let mut world = test_world();
+        let runner = world.spawn(TimeRunner::new(secs(1.))).id();
+        let system = world.register_system(|mut tick_time_runners: TickTimeRunners<()>| {
+            tick_time_runners.tick(0.25);
+        });
+        let started = |world: &mut World| {
+            world.run_system(system).unwrap();
+            world
+                .resource_mut::<Events<TimeRunnerStarted>>()
+                .drain()
+                .count()
+        };
+
+        assert_eq!(started(&mut world), 1);
+        assert_eq!(started(&mut world), 0);
+
+        world
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(true);
+        assert_eq!(started(&mut world), 0);
+        world
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(false);
+        assert_eq!(started(&mut world), 1);
+        assert_eq!(started(&mut world), 0);
+
+        world.get_mut::<TimeRunner>(runner).unwrap().reset();
+        assert_eq!(started(&mut world), 1);
+        assert_eq!(started(&mut world), 0);
+    }
+
+    #[test]
+    fn time_runner_run_conditions() {
+        let mut world = test_world();
+        let runner = world.spawn(TimeRunner::new(secs(1.))).id();
+        let completed = |world: &mut World| {
+            world
+                .run_system_once(is_time_runner_completed(runner))
+                .unwrap()
+        };
+        let paused = |world: &mut World| {
+            world
+                .run_system_once(is_time_runner_paused(runner))
+                .unwrap()
+        };
+
+        assert!(!completed(&mut world));
+        assert!(!paused(&mut world));
+
+        let mut time_runner = world.get_mut::<TimeRunner>(runner).unwrap();
+        time_runner.tick(1.);
+        time_runner.tick(1.);
+        time_runner.set_paused(true);
+        assert!(completed(&mut world));
+        assert!(paused(&mut world));
+
+        world.despawn(runner);
+        assert!(!completed(&mut world));
+        assert!(!paused(&mut world));
+    }
+
+    #[test]
+    fn time_until_complete() {
+        let mut world = test_world();
+        let runner = world.spawn(TimeRunner::new(secs(1.))).id();
+        let infinite = world
+            .spawn(
+                TimeRunner::new(secs(1.))
+                    .with_repeat(Some((Repeat::infinitely(), RepeatStyle::WrapAround))),
+            )
+            .id();
+        let systems = [
+            world.register_system(|mut t: TickTimeRunners<()>| t.tick(0.5)),
+            world.register_system(time_runner_system),
+            world.register_system(time_until_complete_system),
+        ];
+        let update = |world: &mut World| {
+            run_systems(world, &systems);
+        };
+        let secs_left =
+            |world: &World, entity: Entity| world.get::<TimeUntilComplete>(entity).map(|t| t.0);
+
+        update(&mut world);
+        assert_eq!(secs_left(&world, runner), Some(0.5));
+        assert_eq!(secs_left(&world, infinite), Some(f32::INFINITY));
+        update(&mut world);
+        assert_eq!(secs_left(&world, runner), None);
+    }
+
+    #[test]
+    fn time_runner_progress() {
+        let mut world = test_world();
+        let runner = world.spawn(TimeRunner::new(secs(2.))).id();
+        let infinite = world
+            .spawn(
+                TimeRunner::new(secs(1.))
+                    .with_repeat(Some((Repeat::infinitely(), RepeatStyle::WrapAround))),
+            )
+            .id();
+        let systems = [
+            world.register_system(|mut t: TickTimeRunners<()>| t.tick(0.5)),
+            world.register_system(time_runner_system),
+            world.register_system(time_runner_progress_system),
+        ];
+        let update = |world: &mut World| {
+            run_systems(world, &systems);
+        };
+        let progress =
+            |world: &World, entity: Entity| world.get::<TimeRunnerProgress>(entity).map(|p| p.0);
+
+        update(&mut world);
+        assert_eq!(progress(&world, runner), Some(0.25));
+        assert_eq!(progress(&world, infinite), None);
+        update(&mut world);
+        assert_eq!(progress(&world, runner), Some(0.5));
+        for _ in 0..3 {
+            update(&mut world);
+        }
+        assert!(world.get::<TimeRunner>(runner).unwrap().is_completed());
+        assert_eq!(progress(&world, runner), Some(1.));
+    }
+
+    #[test]
+    fn scheduled_pause() {
+        let mut world = test_world();
+        let runner = world
+            .spawn((TimeRunner::new(secs(1.)), ScheduledPause { at_secs: 0.5 }))
+            .id();
+        let systems = [
+            world.register_system(|mut t: TickTimeRunners<()>| t.tick(0.375)),
+            world.register_system(scheduled_pause_system),
+        ];
+        let update = |world: &mut World| {
+            run_systems(world, &systems);
+            let runner = world.get::<TimeRunner>(runner).unwrap();
+            (runner.elasped.now, runner.paused)
+        };
 
-                let span_in_range =
-                    span_in_range(direction, previous_quotient, now_quotient, repeated);
+        assert_eq!(update(&mut world), (0.375, false));
+        assert_eq!(update(&mut world), (0.75, true));
+        assert_eq!(update(&mut world), (0.75, true));
+        assert!(world.get::<ScheduledPause>(runner).is_none());
 
-                if let Some(use_time) = span_in_range {
-                    let span_max = span.max().duration().as_secs_f32();
-                    let span_min = span.min().duration().as_secs_f32();
+        world
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(false);
+        assert_eq!(update(&mut world), (1., false));
+    }
 
-                    let span_length = span_max - span_min;
+    #[test]
+    fn total_elapsed() {
+        let mut world = test_world();
+        let runner = world
+            .spawn((
+                TimeRunner::new(secs(1.))
+                    .with_repeat(Some((Repeat::times(1), RepeatStyle::PingPong))),
+                TotalElapsed::default(),
+            ))
+            .id();
+        let system = world.register_system(|mut t: TickTimeRunners<()>| t.tick(0.375));
+        let total = |world: &World| world.get::<TotalElapsed>(runner).unwrap().0;
 
-                    let new_now = match use_time {
-                        UseTime::Current => runner_elasped_now - span_min,
-                        UseTime::Min => 0.,
-                        UseTime::Max => span_length,
-                    };
-                    let new_previous = runner_elasped_previous - span_min;
+        world.run_system(system).unwrap();
+        assert_eq!(total(&world), 0.375);
+        for _ in 0..3 {
+            world.run_system(system).unwrap();
+        }
+        assert_eq!(total(&world), 1.5);
+        for _ in 0..3 {
+            world.run_system(system).unwrap();
+        }
+        assert_eq!(total(&world), 2.);
 
-                    let new_now_percentage = if span_length > 0. {
-                        new_now / span_length
-                    } else {
-                        match new_now.total_cmp(&0.) {
-                            Ordering::Greater => f32::INFINITY,
-                            Ordering::Equal => match runner_direction {
-                                Forward => f32::INFINITY,
-                                Backward => f32::NEG_INFINITY,
-                            },
-                            Ordering::Less => f32::NEG_INFINITY,
-                        }
-                    };
-                    let new_previous_percentage = if span_length > 0. {
-                        new_previous / span_length
-                    } else {
-                        match new_previous.total_cmp(&0.) {
-                            Ordering::Greater => f32::INFINITY,
-                            Ordering::Equal => match runner_direction {
-                                Forward => f32::INFINITY,
-                                Backward => f32::NEG_INFINITY,
-                            },
-                            Ordering::Less => f32::NEG_INFINITY,
-                        }
-                    };
+        // repeating and completing in the same tick
+        let runner = world
+            .spawn((
+                TimeRunner::new(secs(1.)).with_repeat_times(1),
+                TotalElapsed::default(),
+            ))
+            .id();
+        let system = world.register_system(|mut t: TickTimeRunners<()>| t.tick(3.));
+        world.run_system(system).unwrap();
+        assert_eq!(world.get::<TotalElapsed>(runner).unwrap().0, 2.);
+    }
 
-                    match time_span_progress {
-                        Some(mut time_span_progress) => {
-                            time_span_progress.update(new_now, new_now_percentage);
-                        }
-                        None => {
-                            commands.entity(span_entity).insert(TimeSpanProgress {
-                                now_percentage: new_now_percentage,
-                                now: new_now,
-                                previous_percentage: new_previous_percentage,
-                                previous: new_previous,
-                            });
-                        }
-                    }
-                } else {
-                    commands.entity(span_entity).remove::<TimeSpanProgress>();
-                }
+    #[test]
+    fn repeat_progress() {
+        let mut world = test_world();
+        let runner = world
+            .spawn((
+                TimeRunner::new(secs(1.)).with_repeat_times(2),
+                RepeatProgress::default(),
+            ))
+            .id();
+        let tick = world.register_system(|mut t: TickTimeRunners<()>| t.tick(0.75));
+        let update = world.register_system(repeat_progress_system);
+        let step = |world: &mut World| {
+            world.run_system(tick).unwrap();
+            world.run_system(update).unwrap();
+            *world.get::<RepeatProgress>(runner).unwrap()
+        };
+
+        assert_eq!(
+            step(&mut world),
+            RepeatProgress {
+                cycle_now: 0.75,
+                cycle_previous: 0.,
+                cycle_index: 0
             }
-            runner.collaspe_elasped();
-            if runner.is_completed() {
-                runner_just_completed.push(runner_entity);
+        );
+        assert_eq!(
+            step(&mut world),
+            RepeatProgress {
+                cycle_now: 0.5,
+                cycle_previous: 0.75,
+                cycle_index: 1
             }
-        });
-
-    enum UseTime {
-        Current,
-        Min,
-        Max,
-    }
-
-    fn span_in_range(
-        direction: TimeDirection,
-        previous_quotient: DurationQuotient,
-        now_quotient: DurationQuotient,
-        repeated: Option<RepeatStyle>,
-    ) -> Option<UseTime> {
-        // Look at this behemoth of edge case handling.
-        //
-        // The edge cases are the time when the timer are really short
-        // or delta is really long per frame.
-        //
-        // Currently unknown what happen when timer repeated multiple times in one frame.
-
-        match (
-            direction,
-            previous_quotient,
-            now_quotient,
-            repeated,
-        ) {
-            (_, Inside, Inside, None) => {
-                Some(UseTime::Current)
-            },
-            // ----------------------------------------------------------------
-            | (Forward, Before, Inside, None)
-            | (Forward, Inside, After, None)
-            | (Forward, Before, After, None)
-                => {
-                    Some(UseTime::Current)
-                },
-
-            // ----------------------------------------------------------------
-            | (Backward, After, Inside, None)
-            | (Backward, Inside, Before, None)
-            | (Backward, After, Before, None)
-                => {
-                    Some(UseTime::Current)
-                },
-
-            // -----------------------------------------------------------------
-            // don't remove these comments, may use for debugging in the future
-            | (Forward, Before, Before, Some(WrapAround)) // 1&2 max
-            | (Forward, Inside, Before, Some(WrapAround)) // 1 max
-                => {
-                    // println!("forward wrap use max");
-                    Some(UseTime::Max)
-                },
-            | (Forward, Before, Inside, Some(WrapAround)) // 2 now
-            | (Forward, Before, After, Some(WrapAround)) // 2 now, max
-            | (Forward, Inside, Inside, Some(WrapAround)) // 1&2 now
-            | (Forward, Inside, After, Some(WrapAround)) // 2 now, max
-            | (Forward, After, Inside, Some(WrapAround)) // 1 now 
-            | (Forward, After, After, Some(WrapAround)) // 1&2 now, max
-            // | (Forward, After, Before, Some(WrapAround)) // 1
-                => {
-                    // println!("forward wrap use current");
-                    Some(UseTime::Current)
-                },
+        );
+        assert_eq!(step(&mut world).cycle_index, 2);
+        assert_eq!(step(&mut world).cycle_index, 2);
+    }
 
-            // ----------------------------------------------------------------
-            | (Backward, After, After, Some(WrapAround)) // 1&2 min
-            | (Backward, Inside, After, Some(WrapAround)) // 1 min
-                => {
-                    // println!("backward wrap use min");
-                    Some(UseTime::Min)
-                },
-            | (Backward, Before, Before, Some(WrapAround)) // 1&2 now, min
-            | (Backward, Before, Inside, Some(WrapAround)) // 1 now 
-            | (Backward, Inside, Before, Some(WrapAround)) // 2 now, min
-            | (Backward, Inside, Inside, Some(WrapAround)) // 1&2 now
-            | (Backward, After, Before, Some(WrapAround)) // 2 now, min
-            | (Backward, After, Inside, Some(WrapAround)) // 2 now
-            // | (Backward, Before, After, Some(WrapAround)) // 1
-                => {
-                    // println!("backward wrap use current");
-                    Some(UseTime::Current)
-                },
+    #[test]
+    fn repeat_delay() {
+        let mut world = test_world();
+        let runner = world
+            .spawn((
+                TimeRunner::new(secs(1.))
+                    .with_repeat(Some((Repeat::Infinitely, RepeatStyle::WrapAround))),
+                RepeatDelay(secs(0.5)),
+            ))
+            .id();
+        let system = world.register_system(|mut tick_time_runners: TickTimeRunners<()>| {
+            tick_time_runners.tick(0.25);
+        });
 
-            // ----------------------------------------------------------------
-            | (Backward, Before, Before, Some(PingPong)) // 1&2 now, min
-            | (Backward, Before, Inside, Some(PingPong)) // 1 now
-            | (Backward, Before, After, Some(PingPong)) // 1 now, max
-            | (Backward, Inside, Before, Some(PingPong)) // 2 now, min
-            | (Backward, Inside, Inside, Some(PingPong)) // 1&2 now
-            | (Backward, Inside, After, Some(PingPong)) // 1 now, max
-            | (Backward, After, Before, Some(PingPong)) // 2 now, min
-            | (Backward, After, Inside, Some(PingPong)) // 2 now
-            // | (Backward, After, After, Some(PingPong)) // 1&2
-                => Some(UseTime::Current),
-
-            // ----------------------------------------------------------------
-            // | (Forward, Before, Before, Some(PingPong)) // 1&2
-            | (Forward, Before, Inside, Some(PingPong)) // 2 now
-            | (Forward, Before, After, Some(PingPong)) // 2 now, max
-            | (Forward, Inside, Before, Some(PingPong)) // 1 now, min
-            | (Forward, Inside, Inside, Some(PingPong)) // 1&2 now
-            | (Forward, Inside, After, Some(PingPong)) // 2 now, max
-            | (Forward, After, Before, Some(PingPong)) // 1 now, min
-            | (Forward, After, Inside, Some(PingPong)) // 1 now
-            | (Forward, After, After, Some(PingPong)) // 1&2 now, max
-                => Some(UseTime::Current),
-            _ => None,
+        let mut nows = vec![];
+        for _ in 0..7 {
+            world.run_system(system).unwrap();
+            nows.push(world.get::<TimeRunner>(runner).unwrap().elasped().now);
         }
+        assert_eq!(nows, [0.25, 0.5, 0.75, 0., 0., 0., 0.25]);
     }
-}
 
-#[cfg(test)]
-mod test {
-    use bevy_ecs::system::RunSystemOnce as _;
+    #[test]
+    fn synced_time_runners() {
+        let mut world = World::default();
+        let leader = world.spawn(TimeRunner::new(secs(4.))).id();
+        let followers = [
+            world.spawn(TimeRunner::new(secs(10.))).id(),
+            world.spawn(TimeRunner::new(secs(2.))).id(),
+        ];
+        world.entity_mut(leader).insert(SyncedTimeRunners {
+            leader,
+            followers: followers.to_vec(),
+        });
+        let now =
+            |world: &World, entity: Entity| world.get::<TimeRunner>(entity).unwrap().elasped.now;
 
-    use super::*;
+        world.get_mut::<TimeRunner>(leader).unwrap().tick(1.);
+        world.run_system_once(synced_time_runners_system).unwrap();
+        assert_eq!(now(&world, followers[0]), 2.5);
+        assert_eq!(now(&world, followers[1]), 0.5);
 
-    fn secs(secs: f32) -> Duration {
-        Duration::from_secs_f32(secs)
+        world.get_mut::<TimeRunner>(leader).unwrap().tick(5.);
+        world.run_system_once(synced_time_runners_system).unwrap();
+        assert_eq!(now(&world, followers[0]), 10.);
+        assert_eq!(now(&world, followers[1]), 2.);
     }
 
-    // fn eq(lhs: f32, rhs: f32) -> bool {
-    //     (lhs - rhs).abs() <= f32::EPSILON
-    // }
-
     #[test]
-    fn timer() {
-        let mut timer = TimeRunner::new(secs(5.));
-
-        timer.raw_tick(2.5);
-        assert_eq!(timer.elasped.now, 2.5);
-        assert_eq!(timer.elasped.now_period, 0.5);
-
-        timer.raw_tick(2.5);
-        assert_eq!(timer.elasped.now, 5.);
-        assert_eq!(timer.elasped.now_period, 1.);
+    fn synced_time_runners_across_loop() {
+        let mut world = test_world();
+        let leader = world
+            .spawn(TimeRunner::new(secs(4.)).with_repeat_times(3))
+            .id();
+        let mut span = Entity::PLACEHOLDER;
+        let follower = world
+            .spawn(TimeRunner::new(secs(2.)))
+            .with_children(|c| {
+                span = c
+                    .spawn(TimeSpan::try_from(secs(0.5)..secs(1.5)).unwrap())
+                    .id();
+            })
+            .id();
+        world.entity_mut(leader).insert(SyncedTimeRunners {
+            leader,
+            followers: vec![follower],
+        });
+        let systems = [
+            world.register_system(|mut t: TickTimeRunners<()>| t.tick(1.5)),
+            world.register_system(synced_time_runners_system),
+            world.register_system(time_runner_system),
+        ];
+        let tick = |world: &mut World| {
+            run_systems(world, &systems);
+            let looped = world
+                .resource::<Events<TimeRunnerLooped>>()
+                .iter_current_update_events()
+                .map(|e| e.time_runner)
+                .collect::<Vec<_>>();
+            let entered = world
+                .resource::<Events<TimeSpanEntered>>()
+                .iter_current_update_events()
+                .filter(|e| e.span == span)
+                .count();
+            let events = (looped, entered);
+            world.resource_mut::<Events<TimeRunnerLooped>>().update();
+            world.resource_mut::<Events<TimeSpanEntered>>().update();
+            let follower = world.get::<TimeRunner>(follower).unwrap();
+            (follower.elasped().now(), events)
+        };
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 5.);
-        assert_eq!(timer.elasped.now_period, 1.);
+        assert_eq!(tick(&mut world), (0.75, (vec![], 1)));
+        assert_eq!(tick(&mut world), (1.5, (vec![], 0)));
+        // the leader wraps around without sweeping its follower backward
+        assert_eq!(tick(&mut world), (0.25, (vec![leader], 0)));
+        assert_eq!(tick(&mut world), (1., (vec![], 1)));
+        assert_eq!(
+            world.get::<TimeRunner>(follower).unwrap().repeat(),
+            world.get::<TimeRunner>(leader).unwrap().repeat()
+        );
+    }
 
-        timer.set_tick(0.);
+    #[test]
+    fn time_runner_state() {
+        let mut world = test_world();
+        let runner = world
+            .spawn(TimeRunner::new(secs(1.)).with_paused(true))
+            .id();
+        let systems = [
+            world.register_system(|mut t: TickTimeRunners<()>| t.tick(0.25)),
+            world.register_system(time_runner_system),
+            world.register_system(time_runner_state_system),
+        ];
+        let update = |world: &mut World| {
+            run_systems(world, &systems);
+            *world.get::<TimeRunnerState>(runner).unwrap()
+        };
 
-        timer.raw_tick(3.);
-        assert_eq!(timer.elasped.now, 3.);
-        assert_eq!(timer.elasped.now_period, 3. / 5.);
+        assert_eq!(update(&mut world), TimeRunnerState::Idle);
+        world
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(false);
+        assert_eq!(update(&mut world), TimeRunnerState::Running);
+        world
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(true);
+        assert_eq!(update(&mut world), TimeRunnerState::Paused);
+        world
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(false);
+        for _ in 0..3 {
+            update(&mut world);
+        }
+        assert_eq!(update(&mut world), TimeRunnerState::Completed);
+    }
 
-        timer.raw_tick(3.);
-        assert_eq!(timer.elasped.now, 5.);
-        assert_eq!(timer.elasped.now_period, 1.);
+    #[test]
+    fn on_time_runner_completed() {
+        #[derive(Resource, Default)]
+        struct Completed(Vec<Entity>);
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 5.);
-        assert_eq!(timer.elasped.now_period, 1.);
+        let mut world = test_world();
+        world.init_resource::<Completed>();
+        let runner = world.spawn(TimeRunner::new(secs(0.5))).id();
+        world.entity_mut(runner).observe(
+            |trigger: Trigger<OnTimeRunnerCompleted>, mut completed: ResMut<Completed>| {
+                completed.0.push(trigger.entity());
+            },
+        );
+        let systems = [
+            world.register_system(|mut t: TickTimeRunners<()>| t.tick(0.25)),
+            world.register_system(time_runner_system),
+        ];
+        for _ in 0..4 {
+            run_systems(&mut world, &systems);
+        }
+        assert_eq!(world.resource::<Completed>().0, [runner]);
     }
 
     #[test]
-    fn timer_backward() {
-        let mut timer = TimeRunner::new(secs(5.));
-        timer.set_direction(TimeDirection::Backward);
-
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 0.);
-        assert_eq!(timer.elasped.now_period, 0.);
+    fn span_events_bubble_to_runner() {
+        #[derive(Resource, Default)]
+        struct Spans {
+            entered: Vec<(Entity, Entity)>,
+            exited: Vec<(Entity, Entity)>,
+        }
 
-        timer.set_tick(5.);
+        let mut world = test_world();
+        world.init_resource::<Spans>();
+        let mut span = Entity::PLACEHOLDER;
+        let runner = world
+            .spawn(TimeRunner::new(secs(1.)))
+            .with_children(|c| {
+                span = c
+                    .spawn(TimeSpan::try_from(secs(0.)..secs(0.5)).unwrap())
+                    .id();
+            })
+            .id();
+        world
+            .entity_mut(runner)
+            .observe(
+                |trigger: Trigger<TimeSpanEntered>, mut spans: ResMut<Spans>| {
+                    spans.entered.push((trigger.entity(), trigger.span));
+                },
+            )
+            .observe(
+                |trigger: Trigger<TimeSpanExited>, mut spans: ResMut<Spans>| {
+                    spans.exited.push((trigger.entity(), trigger.span));
+                },
+            );
+        let systems = [
+            world.register_system(|mut t: TickTimeRunners<()>| t.tick(0.25)),
+            world.register_system(time_runner_system),
+        ];
+        for _ in 0..3 {
+            run_systems(&mut world, &systems);
+        }
+        let spans = world.resource::<Spans>();
+        assert_eq!(spans.entered, [(runner, span)]);
+        assert_eq!(spans.exited, [(runner, span)]);
+    }
 
-        timer.raw_tick(2.5);
-        assert_eq!(timer.elasped.now, 2.5);
-        assert_eq!(timer.elasped.now_period, 0.5);
+    #[test]
+    fn time_runner_cleanup() {
+        let mut world = test_world();
+        let mut child = Entity::PLACEHOLDER;
+        let runner = world
+            .spawn(TimeRunner::new(secs(1.)))
+            .with_children(|c| {
+                child = c
+                    .spawn(TimeSpan::try_from(secs(0.)..secs(1.)).unwrap())
+                    .id();
+            })
+            .id();
+        let referenced = world
+            .spawn((
+                TimeSpan::try_from(secs(0.)..secs(1.)).unwrap(),
+                TimeSpanRef(runner),
+            ))
+            .id();
+        let other_runner = world
+            .spawn(TimeRunner::new(secs(1.)))
+            .with_children(|c| {
+                c.spawn(TimeSpan::try_from(secs(0.)..secs(1.)).unwrap());
+            })
+            .id();
+        let systems = [
+            world.register_system(|mut t: TickTimeRunners<()>| t.tick(0.25)),
+            world.register_system(time_runner_cleanup_system),
+            world.register_system(time_runner_ref_system),
+            world.register_system(time_runner_system),
+        ];
+        let update = |world: &mut World| {
+            run_systems(world, &systems);
+        };
+        update(&mut world);
+        assert!(world.get::<TimeSpanProgress>(child).is_some());
+        assert!(world.get::<TimeSpanProgress>(referenced).is_some());
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 1.5);
-        assert_eq!(timer.elasped.now_period, 1.5 / 5.);
+        world.despawn(runner);
+        update(&mut world);
+        assert!(world.get::<TimeSpanProgress>(child).is_none());
+        assert!(world.get::<TimeSpanProgress>(referenced).is_none());
+        let other_span = world.get::<Children>(other_runner).unwrap()[0];
+        assert!(world.get::<TimeSpanProgress>(other_span).is_some());
+    }
 
-        timer.raw_tick(2.);
-        assert_eq!(timer.elasped.now, 0.);
-        assert_eq!(timer.elasped.now_period, 0.);
+    #[test]
+    fn progress_to_absolute() {
+        let mut world = test_world();
+        let span = TimeSpan::try_from(secs(1.)..secs(3.)).unwrap();
+        let mut span_entity = Entity::PLACEHOLDER;
+        let runner = world
+            .spawn(TimeRunner::new(secs(4.)))
+            .with_children(|c| {
+                span_entity = c.spawn(span).id();
+            })
+            .id();
+        let system = world.register_system(time_runner_system);
+        for _ in 0..5 {
+            world.get_mut::<TimeRunner>(runner).unwrap().tick(0.5);
+            world.run_system(system).unwrap();
+            let now = world.get::<TimeRunner>(runner).unwrap().elasped().now;
+            if let Some(progress) = world.get::<TimeSpanProgress>(span_entity) {
+                assert_eq!(progress.to_absolute_secs(&span), now);
+                assert_eq!(
+                    progress.to_absolute_duration(&span),
+                    Duration::from_secs_f32(now)
+                );
+            }
+        }
+        assert!(world.get::<TimeSpanProgress>(span_entity).is_some());
     }
 
     #[test]
-    fn timer_wrap_around() {
-        let mut timer = TimeRunner::new(secs(5.));
-        timer.set_repeat(Some((Repeat::Infinitely, RepeatStyle::WrapAround)));
+    fn time_span_ref() {
+        let mut world = test_world();
+        let runner = world.spawn(TimeRunner::new(secs(1.))).id();
+        let span = world
+            .spawn((
+                TimeSpan::try_from(secs(0.5)..secs(1.)).unwrap(),
+                TimeSpanRef(runner),
+            ))
+            .id();
+        let systems = [
+            world.register_system(|mut t: TickTimeRunners<()>| t.tick(0.25)),
+            world.register_system(time_runner_ref_system),
+            world.register_system(time_runner_system),
+        ];
+        let update = |world: &mut World| {
+            run_systems(world, &systems);
+            world.get::<TimeSpanProgress>(span).map(|p| p.now)
+        };
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 1.);
-        assert_eq!(timer.elasped.now_period, 1. / 5.);
+        assert_eq!(update(&mut world), None);
+        assert_eq!(update(&mut world), Some(0.));
+        assert_eq!(update(&mut world), Some(0.25));
+        assert_eq!(update(&mut world), Some(0.5));
+        assert_eq!(update(&mut world), None);
+        assert_eq!(world.resource::<Events<TimeSpanExited>>().len(), 1);
+    }
 
-        timer.raw_tick(2.5);
-        assert_eq!(timer.elasped.now, 3.5);
-        assert_eq!(timer.elasped.now_period, 3.5 / 5.);
+    #[test]
+    fn skip_time_span() {
+        let mut world = test_world();
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 4.5);
-        assert_eq!(timer.elasped.now_period, 4.5 / 5.);
+        let mut spans = [Entity::PLACEHOLDER; 2];
+        let runner = world
+            .spawn(TimeRunner::new(secs(10.)))
+            .with_children(|c| {
+                spans[0] = c
+                    .spawn(TimeSpan::try_from(secs(0.)..secs(10.)).unwrap())
+                    .id();
+                spans[1] = c
+                    .spawn(TimeSpan::try_from(secs(0.)..secs(10.)).unwrap())
+                    .id();
+            })
+            .id();
+        let system = world.register_system(time_runner_system);
+        let tick = |world: &mut World| {
+            world.get_mut::<TimeRunner>(runner).unwrap().tick(1.);
+            world.run_system(system).unwrap();
+        };
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 0.5);
-        assert_eq!(timer.elasped.now_period, 5.5 / 5.);
+        tick(&mut world);
+        assert!(world.get::<TimeSpanProgress>(spans[0]).is_some());
+        assert!(world.get::<TimeSpanProgress>(spans[1]).is_some());
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 1.5);
-        assert_eq!(timer.elasped.now_period, 1.5 / 5.);
+        world.entity_mut(spans[0]).insert(SkipTimeSpan);
+        tick(&mut world);
+        assert!(world.get::<TimeSpanProgress>(spans[0]).is_none());
+        assert_eq!(world.get::<TimeSpanProgress>(spans[1]).unwrap().now, 2.);
+        let exited = world
+            .resource_mut::<Events<TimeSpanExited>>()
+            .drain()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            exited,
+            [TimeSpanExited {
+                span: spans[0],
+                runner
+            }]
+        );
 
-        timer.raw_tick(3.5);
-        assert_eq!(timer.elasped.now, 0.);
-        assert_eq!(timer.elasped.now_period, 5. / 5.);
+        tick(&mut world);
+        assert!(world.get::<TimeSpanProgress>(spans[0]).is_none());
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 1.);
-        assert_eq!(timer.elasped.now_period, 1. / 5.);
+        world.entity_mut(spans[0]).remove::<SkipTimeSpan>();
+        tick(&mut world);
+        assert_eq!(world.get::<TimeSpanProgress>(spans[0]).unwrap().now, 4.);
     }
 
     #[test]
-    fn timer_backward_wrap_around() {
-        let mut timer = TimeRunner::new(secs(5.));
-        timer.set_repeat(Some((Repeat::Infinitely, RepeatStyle::WrapAround)));
-        timer.set_direction(TimeDirection::Backward);
+    fn unchanged_runner_is_not_updated() {
+        let mut world = test_world();
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 4.);
-        assert_eq!(timer.elasped.now_period, -1. / 5.);
+        let mut span = Entity::PLACEHOLDER;
+        let runner = world
+            .spawn(TimeRunner::new(secs(10.)))
+            .with_children(|c| {
+                span = c
+                    .spawn(TimeSpan::try_from(secs(0.)..secs(10.)).unwrap())
+                    .id();
+            })
+            .id();
+        let system = world.register_system(time_runner_system);
+        let progress_changed =
+            world.register_system(move |q_span: Query<(), Changed<TimeSpanProgress>>| {
+                q_span.contains(span)
+            });
 
-        timer.raw_tick(2.5);
-        assert_eq!(timer.elasped.now, 1.5);
-        assert_eq!(timer.elasped.now_period, 1.5 / 5.);
+        world.get_mut::<TimeRunner>(runner).unwrap().tick(1.);
+        world.run_system(system).unwrap();
+        assert!(world.run_system(progress_changed).unwrap());
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 0.5);
-        assert_eq!(timer.elasped.now_period, 0.5 / 5.);
+        world.get_mut::<TimeRunner>(runner).unwrap().tick(1.);
+        world.run_system(system).unwrap();
+        assert!(world.run_system(progress_changed).unwrap());
+        assert_eq!(world.get::<TimeSpanProgress>(span).unwrap().now, 2.);
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 4.5);
-        assert_eq!(timer.elasped.now_period, -0.5 / 5.);
+        world.run_system(system).unwrap();
+        assert!(!world.run_system(progress_changed).unwrap());
     }
 
     #[test]
-    fn timer_wrap_around_times() {
-        let mut timer = TimeRunner::new(secs(5.));
-        timer.set_repeat(Some((Repeat::times(2), RepeatStyle::WrapAround)));
-
-        timer.raw_tick(4.);
-        assert_eq!(timer.elasped.now, 4.);
-        assert_eq!(timer.elasped.now_period, 4. / 5.);
-        assert_eq!(
-            timer.repeat.unwrap().0,
-            Repeat::Times {
-                times: 2,
-                times_repeated: 0
-            },
-        );
+    fn paused_runner_updates_changed_spans() {
+        let mut world = test_world();
+        let runner = world.spawn(TimeRunner::new(secs(10.))).id();
+        let system = world.register_system(time_runner_system);
+        world.get_mut::<TimeRunner>(runner).unwrap().tick(5.);
+        world.run_system(system).unwrap();
+        world
+            .get_mut::<TimeRunner>(runner)
+            .unwrap()
+            .set_paused(true);
+        world.run_system(system).unwrap();
 
-        timer.raw_tick(4.);
-        assert_eq!(timer.elasped.now, 3.);
-        assert_eq!(timer.elasped.now_period, 8. / 5.);
-        assert_eq!(
-            timer.repeat.unwrap().0,
-            Repeat::Times {
-                times: 2,
-                times_repeated: 1
-            },
-        );
+        let span = world
+            .spawn(TimeSpan::try_from(secs(4.)..secs(6.)).unwrap())
+            .set_parent(runner)
+            .id();
+        world.run_system(system).unwrap();
+        assert_eq!(world.get::<TimeSpanProgress>(span).unwrap().now, 1.);
 
-        timer.raw_tick(4.);
-        assert_eq!(timer.elasped.now, 2.);
-        assert_eq!(timer.elasped.now_period, 7. / 5.);
-        assert_eq!(
-            timer.repeat.unwrap().0,
-            Repeat::Times {
-                times: 2,
-                times_repeated: 2
-            },
-        );
+        *world.get_mut::<TimeSpan>(span).unwrap() = TimeSpan::try_from(secs(3.)..secs(6.)).unwrap();
+        world.run_system(system).unwrap();
+        assert_eq!(world.get::<TimeSpanProgress>(span).unwrap().now, 2.);
 
-        timer.raw_tick(4.);
-        assert_eq!(timer.elasped.now, 5.);
-        assert_eq!(timer.elasped.now_period, 1.);
-        assert_eq!(
-            timer.repeat.unwrap().0,
-            Repeat::Times {
-                times: 2,
-                times_repeated: 2
-            },
-        );
+        world.entity_mut(span).insert(SkipTimeSpan);
+        world.run_system(system).unwrap();
+        assert!(world.get::<TimeSpanProgress>(span).is_none());
+        world.entity_mut(span).remove::<SkipTimeSpan>();
+        world.run_system(system).unwrap();
+        assert!(world.get::<TimeSpanProgress>(span).is_some());
 
-        timer.raw_tick(1.);
-        assert_eq!(timer.elasped.now, 5.);
-        assert_eq!(timer.elasped.now_period, 1.);
-        assert_eq!(
-            timer.repeat.unwrap().0,
-            Repeat::Times {
-                times: 2,
-                times_repeated: 2
-            },
-        );
+        world.entity_mut(runner).insert(SkipTimeRunner);
+        world.run_system(system).unwrap();
+        assert!(world.get::<TimeSpanProgress>(span).is_none());
+        world.entity_mut(runner).remove::<SkipTimeRunner>();
+        world.run_system(system).unwrap();
+        assert!(world.get::<TimeSpanProgress>(span).is_some());
     }
 
     #[test]
-    fn timer_backward_wrap_around_times() {
-        let mut timer = TimeRunner::new(secs(5.));
-        timer.set_repeat(Some((Repeat::times(2), RepeatStyle::WrapAround)));
-        timer.set_direction(TimeDirection::Backward);
+    fn many_paused_runners_are_not_updated() {
+        let mut world = test_world();
+        for _ in 0..10_000 {
+            world.spawn(TimeRunner::new(secs(1.))).with_children(|c| {
+                c.spawn(TimeSpan::try_from(secs(0.)..secs(1.)).unwrap());
+            });
+        }
+        let systems = [
+            world.register_system(|mut t: TickTimeRunners<()>| t.tick(0.5)),
+            world.register_system(time_runner_system),
+        ];
+        let progress_changed = world
+            .register_system(|q_span: Query<(), Changed<TimeSpanProgress>>| q_span.iter().count());
+        run_systems(&mut world, &systems);
+        assert_eq!(world.run_system(progress_changed).unwrap(), 10_000);
 
-        timer.raw_tick(4.);
-        assert_eq!(timer.elasped.now, 1.);
-        assert_eq!(timer.elasped.now_period, -4. / 5.);
-        assert_eq!(
-            timer.repeat.unwrap().0,
-            Repeat::Times {
-                times: 2,
-                times_repeated: 1
-            },
-        );
+        world
+            .query::<&mut TimeRunner>()
+            .iter_mut(&mut world)
+            .for_each(|mut runner| {
+                runner.set_paused(true);
+            });
+        run_systems(&mut world, &systems);
+        world.run_system(progress_changed).unwrap();
+        run_systems(&mut world, &systems);
+        assert_eq!(world.run_system(progress_changed).unwrap(), 0);
+    }
 
-        timer.raw_tick(4.);
-        assert_eq!(timer.elasped.now, 2.);
-        assert_eq!(timer.elasped.now_period, -3. / 5.);
-        assert_eq!(
-            timer.repeat.unwrap().0,
-            Repeat::Times {
-                times: 2,
-                times_repeated: 2
-            },
-        );
+    #[test]
+    fn time_span_active_first_frame() {
+        for direction in [TimeDirection::Forward, TimeDirection::Backward] {
+            let mut world = test_world();
 
-        timer.raw_tick(4.);
-        assert_eq!(timer.elasped.now, 0.);
-        assert_eq!(timer.elasped.now_period, 0. / 5.);
-        assert_eq!(
-            timer.repeat.unwrap().0,
-            Repeat::Times {
-                times: 2,
-                times_repeated: 2
-            },
-        );
+            let mut span = Entity::PLACEHOLDER;
+            let mut runner = TimeRunner::new(secs(10.)).with_direction(direction);
+            runner.reset();
+            let runner = world
+                .spawn(runner)
+                .with_children(|c| {
+                    span = c
+                        .spawn(TimeSpan::try_from(secs(2.)..secs(8.)).unwrap())
+                        .id();
+                })
+                .id();
+            let system = world.register_system(time_runner_system);
+            let is_active_first_frame =
+                world.register_system(|In(span): In<Entity>, q: Query<Ref<TimeSpanProgress>>| {
+                    TimeSpanProgress::is_active_first_frame(&q.get(span).unwrap())
+                });
+            let first_frame = |world: &mut World| {
+                world.get_mut::<TimeRunner>(runner).unwrap().tick(3.);
+                world.run_system(system).unwrap();
+                world
+                    .run_system_with_input(is_active_first_frame, span)
+                    .unwrap()
+            };
+
+            assert!(first_frame(&mut world), "{direction:?}");
+            assert!(!first_frame(&mut world), "{direction:?}");
+        }
     }
 
     #[test]
-    fn timer_ping_pong() {
-        let mut timer = TimeRunner::new(secs(5.));
-        timer.set_repeat(Some((Repeat::Infinitely, RepeatStyle::PingPong)));
-
-        timer.raw_tick(3.);
-        assert_eq!(timer.elasped.now, 3.);
-        assert_eq!(timer.elasped.now_period, 3. / 5.);
-        assert_eq!(timer.direction, TimeDirection::Forward);
+    fn time_span_entered_exited() {
+        let mut world = test_world();
 
-        timer.raw_tick(3.);
-        assert_eq!(timer.elasped.now, 4.);
-        assert_eq!(timer.elasped.now_period, 6. / 5.);
-        assert_eq!(timer.direction, TimeDirection::Backward);
+        let mut spans = [Entity::PLACEHOLDER; 2];
+        let runner = world
+            .spawn(TimeRunner::new(secs(10.)))
+            .with_children(|c| {
+                spans[0] = c
+                    .spawn(TimeSpan::try_from(secs(0.)..secs(5.)).unwrap())
+                    .id();
+                spans[1] = c
+                    .spawn(TimeSpan::try_from(secs(5.)..=secs(10.)).unwrap())
+                    .id();
+            })
+            .id();
+        let system = world.register_system(time_runner_system);
+        let tick = |world: &mut World, secs: f32| {
+            world.get_mut::<TimeRunner>(runner).unwrap().tick(secs);
+            world.run_system(system).unwrap();
+            let entered = world
+                .resource_mut::<Events<TimeSpanEntered>>()
+                .drain()
+                .map(|e| e.span)
+                .collect::<Vec<_>>();
+            let exited = world
+                .resource_mut::<Events<TimeSpanExited>>()
+                .drain()
+                .map(|e| e.span)
+                .collect::<Vec<_>>();
+            (entered, exited)
+        };
 
-        timer.raw_tick(3.);
-        assert_eq!(timer.elasped.now, 1.);
-        assert_eq!(timer.elasped.now_period, 1. / 5.);
-        assert_eq!(timer.direction, TimeDirection::Backward);
+        assert_eq!(tick(&mut world, 1.), (vec![spans[0]], vec![]));
+        assert_eq!(tick(&mut world, 1.), (vec![], vec![]));
+        assert_eq!(tick(&mut world, 4.), (vec![spans[1]], vec![]));
+        assert_eq!(tick(&mut world, 4.), (vec![], vec![spans[0]]));
+        // completed
+        assert_eq!(tick(&mut world, 0.), (vec![], vec![spans[1]]));
+    }
 
-        timer.raw_tick(3.);
-        assert_eq!(timer.elasped.now, 2.);
-        assert_eq!(timer.elasped.now_period, -2. / 5.);
-        assert_eq!(timer.direction, TimeDirection::Forward);
+    #[test]
+    fn time_span_state() {
+        let mut world = test_world();
 
-        timer.raw_tick(3.);
-        assert_eq!(timer.elasped.now, 5.);
-        assert_eq!(timer.elasped.now_period, 5. / 5.);
-        assert_eq!(timer.direction, TimeDirection::Backward);
+        let mut spans = [Entity::PLACEHOLDER; 2];
+        let runner = world
+            .spawn(TimeRunner::new(secs(10.)))
+            .with_children(|c| {
+                spans[0] = c
+                    .spawn(TimeSpan::try_from(secs(0.)..secs(5.)).unwrap())
+                    .id();
+                spans[1] = c
+                    .spawn(TimeSpan::try_from(secs(5.)..=secs(10.)).unwrap())
+                    .id();
+            })
+            .id();
+        let system = world.register_system(time_runner_system);
+        let mut q_changed = world.query_filtered::<Entity, Changed<TimeSpanState>>();
+        let mut tick = |world: &mut World, secs: f32| {
+            world.get_mut::<TimeRunner>(runner).unwrap().tick(secs);
+            world.run_system(system).unwrap();
+            let states = spans.map(|span| *world.get::<TimeSpanState>(span).unwrap());
+            let changed = q_changed.iter(world).count();
+            world.clear_trackers();
+            (states, changed)
+        };
 
-        timer.raw_tick(3.);
-        assert_eq!(timer.elasped.now, 2.);
-        assert_eq!(timer.elasped.now_period, 2. / 5.);
-        assert_eq!(timer.direction, TimeDirection::Backward);
+        use TimeSpanState::*;
+        assert_eq!(tick(&mut world, 1.), ([Active, Before], 2));
+        assert_eq!(tick(&mut world, 1.), ([Active, Before], 0));
+        assert_eq!(tick(&mut world, 4.), ([After, Active], 2));
+        assert_eq!(tick(&mut world, 4.), ([After, Active], 0));
+        assert_eq!(tick(&mut world, 0.), ([After, After], 1));
     }
 
     // There's no test for repeating ones yet and I bet most of them is wrong.
     #[test]
     fn timer_big_tick() {
-        let mut world = World::default();
+        let mut world = test_world();
 
         let mut time_runner = TimeRunner::new(secs(10.));
         time_runner.tick(10.);
@@ -993,7 +4170,7 @@ mod test {
 
     #[test]
     fn timer_zero_length_span() {
-        let mut world = World::default();
+        let mut world = test_world();
 
         let mut time_runner = TimeRunner::new(secs(4.));
         time_runner.tick(4.);
@@ -0,0 +1,51 @@
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+
+/// Provides delta time for [`tick_time_runner_system`](crate::tick_time_runner_system).
+///
+/// Insert as a [`Box<dyn TimeSource>`] resource or use
+/// [`TimeRunnerPlugin::with_time_source`](crate::TimeRunnerPlugin::with_time_source)
+/// to drive runners from an audio clock, a network-synchronized frame counter, etc.
+pub trait TimeSource: Send + Sync + 'static {
+    /// Seconds to tick all runners by for the current frame.
+    fn delta_secs(&self, world: &World) -> f32;
+}
+
+impl Resource for Box<dyn TimeSource> {}
+
+/// Delta seconds from the [`TimeSource`] resource, or [`VirtualTimeSource`]
+/// if there's none.
+pub(crate) fn time_source_delta(world: &World) -> f32 {
+    match world.get_resource::<Box<dyn TimeSource>>() {
+        Some(time_source) => time_source.delta_secs(world),
+        None => VirtualTimeSource.delta_secs(world),
+    }
+}
+
+impl std::fmt::Debug for dyn TimeSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("dyn TimeSource")
+    }
+}
+
+/// [`TimeSource`] using [`Time<Real>`].
+/// Runners will keep ticking even if virtual time is paused.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RealTimeSource;
+
+impl TimeSource for RealTimeSource {
+    fn delta_secs(&self, world: &World) -> f32 {
+        world.resource::<Time<Real>>().delta_secs()
+    }
+}
+
+/// [`TimeSource`] using [`Time<Virtual>`].
+/// Runners will respect virtual time pausing and relative speed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VirtualTimeSource;
+
+impl TimeSource for VirtualTimeSource {
+    fn delta_secs(&self, world: &World) -> f32 {
+        world.resource::<Time<Virtual>>().delta_secs()
+    }
+}
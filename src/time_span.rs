@@ -2,7 +2,11 @@ use std::cmp::Ordering;
 use std::ops;
 use std::time::Duration;
 
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    component::{Component, StorageType},
+    prelude::*,
+};
+use bevy_hierarchy::prelude::*;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::prelude::*;
 
@@ -10,11 +14,12 @@ use bevy_reflect::prelude::*;
 /// checked.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TimeBound {
     /// Inclusively check this duration
-    Inclusive(Duration),
+    Inclusive(#[cfg_attr(feature = "serde", serde(with = "crate::serde_duration"))] Duration),
     /// Exclusively check this duration
-    Exclusive(Duration),
+    Exclusive(#[cfg_attr(feature = "serde", serde(with = "crate::serde_duration"))] Duration),
 }
 
 impl TimeBound {
@@ -24,6 +29,34 @@ impl TimeBound {
             TimeBound::Inclusive(d) | TimeBound::Exclusive(d) => *d,
         }
     }
+
+    /// Returns true if this is [`TimeBound::Inclusive`]
+    pub fn is_inclusive(&self) -> bool {
+        matches!(self, TimeBound::Inclusive(_))
+    }
+
+    /// Returns true if this is [`TimeBound::Exclusive`]
+    pub fn is_exclusive(&self) -> bool {
+        matches!(self, TimeBound::Exclusive(_))
+    }
+
+    /// [`TimeBound::Inclusive`] with the same duration
+    pub fn to_inclusive(&self) -> TimeBound {
+        TimeBound::Inclusive(self.duration())
+    }
+
+    /// [`TimeBound::Exclusive`] with the same duration
+    pub fn to_exclusive(&self) -> TimeBound {
+        TimeBound::Exclusive(self.duration())
+    }
+
+    /// The same bound with a different duration
+    fn with_duration(&self, duration: Duration) -> TimeBound {
+        match self {
+            TimeBound::Inclusive(_) => TimeBound::Inclusive(duration),
+            TimeBound::Exclusive(_) => TimeBound::Exclusive(duration),
+        }
+    }
 }
 
 impl Default for TimeBound {
@@ -50,6 +83,15 @@ pub enum NewTimeSpanError {
         #[allow(missing_docs)]
         max: TimeBound,
     },
+    /// The provided percentage is not within 0–1.
+    PercentageOutOfRange {
+        #[allow(missing_docs)]
+        start: f32,
+        #[allow(missing_docs)]
+        end: f32,
+    },
+    /// A bound of the span is too large to be kept in a [`Duration`].
+    Overflow,
 }
 
 impl std::error::Error for NewTimeSpanError {}
@@ -68,6 +110,15 @@ impl std::fmt::Display for NewTimeSpanError {
                     "This span has min greater than max: min {min:?} max {max:?}"
                 )
             }
+            NewTimeSpanError::PercentageOutOfRange { start, end } => {
+                write!(
+                    f,
+                    "This span has percentage not within 0–1: start {start} end {end}"
+                )
+            }
+            NewTimeSpanError::Overflow => {
+                write!(f, "This span has a bound that overflows Duration")
+            }
         }
     }
 }
@@ -76,15 +127,37 @@ impl std::fmt::Display for NewTimeSpanError {
 #[derive(Debug, Component, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 #[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "TimeSpanUnchecked")
+)]
 pub struct TimeSpan {
     /// Minimum time of this time span.
     min: TimeBound,
     /// Maximum time of this time span.
     max: TimeBound,
 }
+
+/// [`TimeSpan`] to be validated on deserialize.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct TimeSpanUnchecked {
+    min: TimeBound,
+    max: TimeBound,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TimeSpanUnchecked> for TimeSpan {
+    type Error = NewTimeSpanError;
+
+    fn try_from(span: TimeSpanUnchecked) -> Result<Self, Self::Error> {
+        TimeSpan::new(span.min, span.max)
+    }
+}
 impl TimeSpan {
     /// Create a new [`TimeSpan`] unchecked for invalid min, max.
-    pub(crate) fn new_unchecked(min: TimeBound, max: TimeBound) -> TimeSpan {
+    pub(crate) const fn new_unchecked(min: TimeBound, max: TimeBound) -> TimeSpan {
         TimeSpan { min, max }
     }
 
@@ -102,6 +175,83 @@ impl TimeSpan {
         Ok(Self::new_unchecked(min, max))
     }
 
+    /// Create a new [`TimeSpan`] covering the whole runner, `0..=runner_length`.
+    pub const fn full(runner_length: Duration) -> TimeSpan {
+        TimeSpan::new_unchecked(
+            TimeBound::Inclusive(Duration::ZERO),
+            TimeBound::Inclusive(runner_length),
+        )
+    }
+
+    /// Create a new [`TimeSpan`] from `start` to the end of the runner,
+    /// `start..runner_length`.
+    ///
+    /// This is what `start..` would mean since [`ops::RangeFrom`] can't be
+    /// converted without knowing the runner length.
+    pub fn from_start(
+        start: Duration,
+        runner_length: Duration,
+    ) -> Result<TimeSpan, NewTimeSpanError> {
+        TimeSpan::try_from(start..runner_length)
+    }
+
+    /// Create a new [`TimeSpan`] that starts at `start` and lasts for `length`,
+    /// `start..start + length`.
+    ///
+    /// Returns [`NewTimeSpanError::NotTime`] if `length` is zero or
+    /// [`NewTimeSpanError::Overflow`] if `start + length` overflows.
+    pub fn from_offset(start: Duration, length: Duration) -> Result<TimeSpan, NewTimeSpanError> {
+        let end = start
+            .checked_add(length)
+            .ok_or(NewTimeSpanError::Overflow)?;
+        let min = TimeBound::Inclusive(start);
+        let max = TimeBound::Exclusive(end);
+        if length.is_zero() {
+            return Err(NewTimeSpanError::NotTime { min, max });
+        }
+        TimeSpan::new(min, max)
+    }
+
+    /// Create a new inclusive [`TimeSpan`] around `center`,
+    /// `center - half_width..=center + half_width`.
+    /// The min saturates at zero if `half_width` is greater than `center`.
+    ///
+    /// Returns [`NewTimeSpanError::Overflow`] if `center + half_width` overflows.
+    pub fn centered_at(
+        center: Duration,
+        half_width: Duration,
+    ) -> Result<TimeSpan, NewTimeSpanError> {
+        let max = center
+            .checked_add(half_width)
+            .ok_or(NewTimeSpanError::Overflow)?;
+        TimeSpan::new(
+            TimeBound::Inclusive(center.saturating_sub(half_width)),
+            TimeBound::Inclusive(max),
+        )
+    }
+
+    /// Create a new [`TimeSpan`] from percentage of `runner_length`.
+    /// Inclusive `start` and exclusive `end`, the same as [`ops::Range`].
+    ///
+    /// Returns error if `start >= end` or either value is not within 0–1.
+    pub fn from_percentage_range(
+        start: f32,
+        end: f32,
+        runner_length: Duration,
+    ) -> Result<TimeSpan, NewTimeSpanError> {
+        if !(0.0..=1.0).contains(&start) || !(0.0..=1.0).contains(&end) {
+            return Err(NewTimeSpanError::PercentageOutOfRange { start, end });
+        }
+        let min = TimeBound::Inclusive(runner_length.mul_f32(start));
+        let max = TimeBound::Exclusive(runner_length.mul_f32(end));
+        if start == end {
+            return Err(NewTimeSpanError::NotTime { min, max });
+        }
+        TimeSpan::new(min, max)
+    }
+
+    /// Where `secs` is relative to this span.
+    /// A [point span](TimeSpan::is_point) is only inside at exactly its point.
     pub(crate) fn quotient(&self, secs: f32) -> DurationQuotient {
         let after_min = match self.min {
             TimeBound::Inclusive(min) => secs >= min.as_secs_f32(),
@@ -119,6 +269,69 @@ impl TimeSpan {
         }
     }
 
+    /// Returns true if `time` is within this span, respecting the bounds.
+    pub fn contains(&self, time: Duration) -> bool {
+        let after_min = match self.min {
+            TimeBound::Inclusive(min) => time >= min,
+            TimeBound::Exclusive(min) => time > min,
+        };
+        let before_max = match self.max {
+            TimeBound::Inclusive(max) => time <= max,
+            TimeBound::Exclusive(max) => time < max,
+        };
+        after_min && before_max
+    }
+
+    /// Returns true if this span is a single point in time, `t..=t`.
+    /// This is the only valid zero-length span.
+    pub fn is_point(&self) -> bool {
+        matches!(
+            (self.min, self.max),
+            (TimeBound::Inclusive(min), TimeBound::Inclusive(max)) if min == max
+        )
+    }
+
+    /// Returns true if both spans share any time.
+    /// Spans touching only at an endpoint overlap only if both endpoints are inclusive.
+    pub fn overlaps(&self, other: &TimeSpan) -> bool {
+        let min = stricter_min(self.min, other.min);
+        let max = stricter_max(self.max, other.max);
+        match min.duration().cmp(&max.duration()) {
+            Ordering::Less => true,
+            Ordering::Equal => matches!(
+                (min, max),
+                (TimeBound::Inclusive(_), TimeBound::Inclusive(_))
+            ),
+            Ordering::Greater => false,
+        }
+    }
+
+    /// The time shared by both spans or `None` if they do not overlap.
+    /// Exclusive bound is kept when both spans have a bound at the same time.
+    pub fn intersection(&self, other: &TimeSpan) -> Option<TimeSpan> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        Some(TimeSpan::new_unchecked(
+            stricter_min(self.min, other.min),
+            stricter_max(self.max, other.max),
+        ))
+    }
+
+    /// Split this span into two at `time`.
+    /// The left span ends exclusively at `time` and the right span starts inclusively at `time`.
+    ///
+    /// Returns `None` if `time` is not strictly inside this span.
+    pub fn split_at(&self, time: Duration) -> Option<(TimeSpan, TimeSpan)> {
+        if time <= self.min.duration() || time >= self.max.duration() {
+            return None;
+        }
+        Some((
+            TimeSpan::new_unchecked(self.min, TimeBound::Exclusive(time)),
+            TimeSpan::new_unchecked(TimeBound::Inclusive(time), self.max),
+        ))
+    }
+
     /// Get the min time
     pub fn min(&self) -> TimeBound {
         self.min
@@ -129,12 +342,138 @@ impl TimeSpan {
         self.max
     }
 
+    /// Set the min time. Returns error and keeps this span unchanged if
+    /// the new min is invalid.
+    pub fn set_min(&mut self, min: TimeBound) -> Result<&mut Self, NewTimeSpanError> {
+        *self = TimeSpan::new(min, self.max)?;
+        Ok(self)
+    }
+
+    /// Set the max time. Returns error and keeps this span unchanged if
+    /// the new max is invalid.
+    pub fn set_max(&mut self, max: TimeBound) -> Result<&mut Self, NewTimeSpanError> {
+        *self = TimeSpan::new(self.min, max)?;
+        Ok(self)
+    }
+
+    /// Move the max later by `delta`, keeping the bound kind.
+    pub fn extend_max_by(&mut self, delta: Duration) -> &mut Self {
+        self.max = self.max.with_duration(self.max.duration() + delta);
+        self
+    }
+
+    /// Move the max earlier by `delta`, keeping the bound kind. Returns error
+    /// and keeps this span unchanged if the max would be before the min.
+    pub fn shrink_max_by(&mut self, delta: Duration) -> Result<&mut Self, NewTimeSpanError> {
+        self.set_max(
+            self.max
+                .with_duration(self.max.duration().saturating_sub(delta)),
+        )
+    }
+
+    /// Move the min earlier by `delta`, keeping the bound kind.
+    /// Saturates at zero.
+    pub fn extend_min_by(&mut self, delta: Duration) -> &mut Self {
+        self.min = self
+            .min
+            .with_duration(self.min.duration().saturating_sub(delta));
+        self
+    }
+
+    /// Move the min later by `delta`, keeping the bound kind. Returns error
+    /// and keeps this span unchanged if the min would be after the max.
+    pub fn shrink_min_by(&mut self, delta: Duration) -> Result<&mut Self, NewTimeSpanError> {
+        self.set_min(self.min.with_duration(self.min.duration() + delta))
+    }
+
+    /// Min and max as fractions of `runner_length`.
+    /// Returns `(0., 0.)` if `runner_length` is zero.
+    pub fn normalize_bounds(&self, runner_length: Duration) -> (f32, f32) {
+        if runner_length.is_zero() {
+            return (0., 0.);
+        }
+        let length = runner_length.as_secs_f32();
+        (
+            self.min.duration().as_secs_f32() / length,
+            self.max.duration().as_secs_f32() / length,
+        )
+    }
+
+    /// This span moved later by `delta`, keeping the bound kinds.
+    ///
+    /// Returns [`NewTimeSpanError::Overflow`] if a bound overflows.
+    pub fn shift_by(&self, delta: Duration) -> Result<TimeSpan, NewTimeSpanError> {
+        let shift = |bound: TimeBound| {
+            bound
+                .duration()
+                .checked_add(delta)
+                .map(|duration| bound.with_duration(duration))
+                .ok_or(NewTimeSpanError::Overflow)
+        };
+        TimeSpan::new(shift(self.min)?, shift(self.max)?)
+    }
+
+    /// This span moved earlier by `delta`, keeping the bound kinds.
+    /// Each bound saturates at zero so the span may get shorter or become invalid.
+    pub fn shift_backward_by(&self, delta: Duration) -> Result<TimeSpan, NewTimeSpanError> {
+        TimeSpan::new(
+            self.min
+                .with_duration(self.min.duration().saturating_sub(delta)),
+            self.max
+                .with_duration(self.max.duration().saturating_sub(delta)),
+        )
+    }
+
     /// `self.max.duration() - self.min.duration()`
     pub fn length(&self) -> Duration {
         self.max.duration() - self.min.duration()
     }
 }
 
+/// The later of two min bounds. Exclusive wins on the same duration.
+fn stricter_min(a: TimeBound, b: TimeBound) -> TimeBound {
+    match a.duration().cmp(&b.duration()) {
+        Ordering::Less => b,
+        Ordering::Greater => a,
+        Ordering::Equal => match a {
+            TimeBound::Exclusive(_) => a,
+            TimeBound::Inclusive(_) => b,
+        },
+    }
+}
+
+/// The earlier of two max bounds. Exclusive wins on the same duration.
+fn stricter_max(a: TimeBound, b: TimeBound) -> TimeBound {
+    match a.duration().cmp(&b.duration()) {
+        Ordering::Less => a,
+        Ordering::Greater => b,
+        Ordering::Equal => match a {
+            TimeBound::Exclusive(_) => a,
+            TimeBound::Inclusive(_) => b,
+        },
+    }
+}
+
+/// Ordered by min then max. Earlier start sorts first and, on the same start,
+/// earlier end sorts first so shorter spans come before longer ones.
+/// An inclusive min starts earlier than an exclusive min at the same time and
+/// an exclusive max ends earlier than an inclusive max at the same time.
+impl Ord for TimeSpan {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let min_key = |bound: TimeBound| (bound.duration(), bound.is_exclusive());
+        let max_key = |bound: TimeBound| (bound.duration(), bound.is_inclusive());
+        min_key(self.min)
+            .cmp(&min_key(other.min))
+            .then_with(|| max_key(self.max).cmp(&max_key(other.max)))
+    }
+}
+
+impl PartialOrd for TimeSpan {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Default for TimeSpan {
     fn default() -> Self {
         TimeSpan::try_from(Duration::ZERO..Duration::ZERO).unwrap()
@@ -220,6 +559,98 @@ impl TimeSpanProgress {
         }
     }
 
+    /// `now - previous`, positive when progressing forward.
+    #[inline]
+    pub fn delta(&self) -> f32 {
+        self.now - self.previous
+    }
+
+    /// `now_percentage - previous_percentage`, positive when progressing forward.
+    #[inline]
+    pub fn delta_percentage(&self) -> f32 {
+        self.now_percentage - self.previous_percentage
+    }
+
+    /// `now` converted back to the runner time in seconds, `span` being the
+    /// span of this progress.
+    #[inline]
+    pub fn to_absolute_secs(&self, span: &TimeSpan) -> f32 {
+        self.now + span.min().duration().as_secs_f32()
+    }
+
+    /// [`TimeSpanProgress::to_absolute_secs`] as [`Duration`], saturating at zero.
+    #[inline]
+    pub fn to_absolute_duration(&self, span: &TimeSpan) -> Duration {
+        Duration::from_secs_f32(self.to_absolute_secs(span).max(0.))
+    }
+
+    /// Returns true if the progress just came into 0–1 from outside,
+    /// which is the case on the frame a span becomes active.
+    pub fn is_entering(&self) -> bool {
+        (self.previous_percentage < 0. && self.now_percentage >= 0.)
+            || (self.previous_percentage > 1. && self.now_percentage <= 1.)
+    }
+
+    /// Returns true if the progress just went out of 0–1 from inside.
+    ///
+    /// A span leaving its range still receives this last overshooting progress
+    /// and only has [`TimeSpanProgress`] removed in the next update, so this can be
+    /// checked directly without any extra component.
+    pub fn is_exiting(&self) -> bool {
+        (self.now_percentage > 1. && self.previous_percentage <= 1.)
+            || (self.now_percentage < 0. && self.previous_percentage >= 0.)
+    }
+
+    /// `f` applied to `now_percentage`
+    #[inline]
+    pub fn map_now_percentage(&self, f: impl Fn(f32) -> f32) -> f32 {
+        f(self.now_percentage)
+    }
+
+    /// `f` applied to `now_percentage` and `previous_percentage` respectively
+    #[inline]
+    pub fn map_both_percentages(&self, f: impl Fn(f32) -> f32) -> (f32, f32) {
+        (f(self.now_percentage), f(self.previous_percentage))
+    }
+
+    /// Returns true if `now_percentage` is within 0–1.
+    pub fn in_range(&self) -> bool {
+        (0. ..=1.).contains(&self.now_percentage)
+    }
+
+    /// Returns true if `now_percentage` is over 1.
+    pub fn is_overshooting(&self) -> bool {
+        self.now_percentage > 1.
+    }
+
+    /// Returns true if `now_percentage` is under 0.
+    pub fn is_undershooting(&self) -> bool {
+        self.now_percentage < 0.
+    }
+
+    /// Copy of this progress with `now_percentage` and `previous_percentage`
+    /// clamped to 0–1.
+    ///
+    /// `now` and `previous` are left unclamped since the span length is not
+    /// known from [`TimeSpanProgress`].
+    pub fn clamped(&self) -> TimeSpanProgress {
+        TimeSpanProgress {
+            now_percentage: self.now_percentage.clamp(0., 1.),
+            previous_percentage: self.previous_percentage.clamp(0., 1.),
+            ..*self
+        }
+    }
+
+    /// Returns true only on the exact frame this span becomes active, which is
+    /// [`Ref::is_added`] since the progress is inserted on activation.
+    ///
+    /// Unlike [`TimeSpanProgress::is_entering`], this doesn't depend on where the
+    /// previous time was so it's also true when the runner seeks or starts
+    /// right inside the span.
+    pub fn is_active_first_frame(progress: &Ref<TimeSpanProgress>) -> bool {
+        progress.is_added()
+    }
+
     pub(crate) fn update(&mut self, now: f32, now_percentage: f32) {
         self.previous_percentage = self.now_percentage;
         self.previous = self.now;
@@ -228,9 +659,130 @@ impl TimeSpanProgress {
     }
 }
 
+/// Where the runner is relative to this [`TimeSpan`], managed alongside
+/// [`TimeSpanProgress`] by its runner or [`TimeSpanGroup`](crate::TimeSpanGroup).
+///
+/// Only changes when the state does so `Changed<TimeSpanState>` can be used to
+/// find spans that just became active or inactive.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub enum TimeSpanState {
+    /// The runner hasn't reached this span
+    #[default]
+    Before,
+    /// The runner is within this span
+    Active,
+    /// The runner has passed this span
+    After,
+}
+
+impl TimeSpanState {
+    /// State from the `now_percentage` of [`TimeSpanProgress`]
+    pub fn from_percentage(percentage: f32) -> TimeSpanState {
+        if percentage < 0. {
+            TimeSpanState::Before
+        } else if percentage > 1. {
+            TimeSpanState::After
+        } else {
+            TimeSpanState::Active
+        }
+    }
+}
+
+impl From<DurationQuotient> for TimeSpanState {
+    fn from(quotient: DurationQuotient) -> Self {
+        match quotient {
+            DurationQuotient::Before => TimeSpanState::Before,
+            DurationQuotient::Inside => TimeSpanState::Active,
+            DurationQuotient::After => TimeSpanState::After,
+        }
+    }
+}
+
+/// Skip a [`TimeSpan`]. [`TimeSpanProgress`] will not be inserted or updated while
+/// this component exists and will be removed when this is inserted.
+#[derive(Debug, Clone, Copy, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct SkipTimeSpan;
+
+/// Name of a [`TimeSpan`] for looking it up with [`get_span_entity_by_label`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct TimeSpanLabel(pub String);
+
+impl From<&str> for TimeSpanLabel {
+    fn from(label: &str) -> Self {
+        TimeSpanLabel(label.to_owned())
+    }
+}
+
+/// Find the first entity with a matching [`TimeSpanLabel`] in `runner` and its
+/// descendants, including spans nested in [`TimeSpanGroup`](crate::TimeSpanGroup).
+pub fn get_span_entity_by_label(
+    label: &str,
+    runner: Entity,
+    q: &Query<(Option<&TimeSpanLabel>, Option<&Children>)>,
+) -> Option<Entity> {
+    let (span_label, children) = q.get(runner).ok()?;
+    if span_label.is_some_and(|span_label| span_label.0 == label) {
+        return Some(runner);
+    }
+    children?
+        .iter()
+        .find_map(|child| get_span_entity_by_label(label, *child, q))
+}
+
+/// Fired when a [`TimeSpan`] becomes active and [`TimeSpanProgress`] is inserted
+///
+/// Also triggered on the span entity for observers and bubbles up through its
+/// [`Parent`]s, so an observer on the runner sees every span entering including
+/// spans in a [`TimeSpanGroup`](crate::TimeSpanGroup).
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimeSpanEntered {
+    /// [`TimeSpan`] that just entered
+    pub span: Entity,
+    /// [`TimeRunner`](crate::TimeRunner) of this span
+    pub runner: Entity,
+}
+
+/// Fired when a [`TimeSpan`] becomes inactive and [`TimeSpanProgress`] is removed
+///
+/// Bubbles up the same way as [`TimeSpanEntered`] when triggered for observers.
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimeSpanExited {
+    /// [`TimeSpan`] that just exited
+    pub span: Entity,
+    /// [`TimeRunner`](crate::TimeRunner) of this span
+    pub runner: Entity,
+}
+
+impl Event for TimeSpanEntered {
+    type Traversal = &'static Parent;
+    const AUTO_PROPAGATE: bool = true;
+}
+
+impl Component for TimeSpanEntered {
+    const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+}
+
+impl Event for TimeSpanExited {
+    type Traversal = &'static Parent;
+    const AUTO_PROPAGATE: bool = true;
+}
+
+impl Component for TimeSpanExited {
+    const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+}
+
 /// Time direciton
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TimeDirection {
     #[default]
     #[allow(missing_docs)]
@@ -238,3 +790,522 @@ pub enum TimeDirection {
     #[allow(missing_docs)]
     Backward,
 }
+
+impl TimeDirection {
+    /// The other direction
+    #[inline]
+    pub const fn opposite(&self) -> TimeDirection {
+        match self {
+            TimeDirection::Forward => TimeDirection::Backward,
+            TimeDirection::Backward => TimeDirection::Forward,
+        }
+    }
+
+    /// `1.` for [`TimeDirection::Forward`] and `-1.` for [`TimeDirection::Backward`]
+    pub fn as_f32(&self) -> f32 {
+        match self {
+            TimeDirection::Forward => 1.,
+            TimeDirection::Backward => -1.,
+        }
+    }
+
+    /// Direction of the sign of `v`.
+    /// Returns `None` if `v` is zero or NaN.
+    pub fn from_f32(v: f32) -> Option<TimeDirection> {
+        if v > 0. {
+            Some(TimeDirection::Forward)
+        } else if v < 0. {
+            Some(TimeDirection::Backward)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn span_label() {
+        use bevy_ecs::system::RunSystemOnce as _;
+
+        let mut world = World::default();
+        let mut spans = [Entity::PLACEHOLDER; 2];
+        let runner = world
+            .spawn_empty()
+            .with_children(|c| {
+                spans[0] = c.spawn(TimeSpanLabel::from("intro")).id();
+                c.spawn_empty().with_children(|c| {
+                    spans[1] = c.spawn(TimeSpanLabel::from("outro")).id();
+                });
+            })
+            .id();
+        let find = move |label: &'static str| {
+            move |q: Query<(Option<&TimeSpanLabel>, Option<&Children>)>| {
+                get_span_entity_by_label(label, runner, &q)
+            }
+        };
+
+        assert_eq!(
+            world.run_system_once(find("intro")).unwrap(),
+            Some(spans[0])
+        );
+        assert_eq!(
+            world.run_system_once(find("outro")).unwrap(),
+            Some(spans[1])
+        );
+        assert_eq!(world.run_system_once(find("missing")).unwrap(), None);
+    }
+
+    #[test]
+    fn direction_opposite() {
+        assert_eq!(TimeDirection::Forward.opposite(), TimeDirection::Backward);
+        assert_eq!(TimeDirection::Backward.opposite(), TimeDirection::Forward);
+    }
+
+    #[test]
+    fn direction_f32() {
+        assert_eq!(TimeDirection::Forward.as_f32(), 1.);
+        assert_eq!(TimeDirection::Backward.as_f32(), -1.);
+        assert_eq!(TimeDirection::from_f32(0.5), Some(TimeDirection::Forward));
+        assert_eq!(TimeDirection::from_f32(-2.), Some(TimeDirection::Backward));
+        assert_eq!(TimeDirection::from_f32(0.), None);
+        assert_eq!(TimeDirection::from_f32(-0.), None);
+        assert_eq!(TimeDirection::from_f32(f32::NAN), None);
+    }
+
+    #[test]
+    fn full() {
+        assert_eq!(
+            TimeSpan::full(secs(10)),
+            TimeSpan::try_from(secs(0)..=secs(10)).unwrap()
+        );
+    }
+
+    #[test]
+    fn ord() {
+        let mut spans = vec![
+            TimeSpan::try_from(secs(2)..secs(3)).unwrap(),
+            TimeSpan::try_from(secs(0)..=secs(2)).unwrap(),
+            TimeSpan::try_from(secs(0)..secs(2)).unwrap(),
+            TimeSpan::try_from(secs(0)..secs(1)).unwrap(),
+            TimeSpan::new(TimeBound::Exclusive(secs(0)), TimeBound::Exclusive(secs(1))).unwrap(),
+        ];
+        spans.sort();
+        assert_eq!(
+            spans,
+            vec![
+                TimeSpan::try_from(secs(0)..secs(1)).unwrap(),
+                TimeSpan::try_from(secs(0)..secs(2)).unwrap(),
+                TimeSpan::try_from(secs(0)..=secs(2)).unwrap(),
+                TimeSpan::new(TimeBound::Exclusive(secs(0)), TimeBound::Exclusive(secs(1)))
+                    .unwrap(),
+                TimeSpan::try_from(secs(2)..secs(3)).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let span = TimeSpan::try_from(secs(2)..secs(4)).unwrap();
+        assert!(!span.contains(Duration::from_secs_f32(1.9)));
+        assert!(span.contains(secs(2)));
+        assert!(span.contains(secs(3)));
+        assert!(!span.contains(secs(4)));
+
+        let span =
+            TimeSpan::new(TimeBound::Exclusive(secs(2)), TimeBound::Inclusive(secs(4))).unwrap();
+        assert!(!span.contains(secs(2)));
+        assert!(span.contains(secs(4)));
+    }
+
+    #[test]
+    fn time_bound_kind() {
+        let inclusive = TimeBound::Inclusive(secs(1));
+        let exclusive = TimeBound::Exclusive(secs(1));
+        assert!(inclusive.is_inclusive() && !inclusive.is_exclusive());
+        assert!(exclusive.is_exclusive() && !exclusive.is_inclusive());
+        assert_eq!(inclusive.to_exclusive(), exclusive);
+        assert_eq!(exclusive.to_inclusive(), inclusive);
+        assert_eq!(inclusive.to_inclusive(), inclusive);
+    }
+
+    #[test]
+    fn is_point() {
+        assert!(TimeSpan::try_from(secs(2)..=secs(2)).unwrap().is_point());
+        assert!(!TimeSpan::try_from(secs(2)..=secs(3)).unwrap().is_point());
+        assert!(!TimeSpan::try_from(secs(2)..secs(3)).unwrap().is_point());
+    }
+
+    #[test]
+    fn overlaps() {
+        use TimeBound::*;
+
+        let span = |min: TimeBound, max: TimeBound| TimeSpan::new(min, max).unwrap();
+        let check = |a: TimeSpan, b: TimeSpan, expected: bool| {
+            assert_eq!(a.overlaps(&b), expected, "{a:?} {b:?}");
+            assert_eq!(b.overlaps(&a), expected, "{b:?} {a:?}");
+        };
+
+        check(
+            span(Inclusive(secs(0)), Exclusive(secs(4))),
+            span(Inclusive(secs(2)), Exclusive(secs(6))),
+            true,
+        );
+        check(
+            span(Inclusive(secs(0)), Exclusive(secs(2))),
+            span(Inclusive(secs(3)), Exclusive(secs(6))),
+            false,
+        );
+        check(
+            span(Inclusive(secs(0)), Exclusive(secs(6))),
+            span(Inclusive(secs(2)), Exclusive(secs(3))),
+            true,
+        );
+
+        // touching at 2 seconds
+        check(
+            span(Inclusive(secs(0)), Inclusive(secs(2))),
+            span(Inclusive(secs(2)), Inclusive(secs(4))),
+            true,
+        );
+        check(
+            span(Inclusive(secs(0)), Exclusive(secs(2))),
+            span(Inclusive(secs(2)), Inclusive(secs(4))),
+            false,
+        );
+        check(
+            span(Inclusive(secs(0)), Inclusive(secs(2))),
+            span(Exclusive(secs(2)), Inclusive(secs(4))),
+            false,
+        );
+        check(
+            span(Inclusive(secs(0)), Exclusive(secs(2))),
+            span(Exclusive(secs(2)), Inclusive(secs(4))),
+            false,
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        use TimeBound::*;
+
+        let a = TimeSpan::new(Inclusive(secs(0)), Inclusive(secs(4))).unwrap();
+        let b = TimeSpan::new(Exclusive(secs(2)), Exclusive(secs(4))).unwrap();
+        let c = TimeSpan::new(Inclusive(secs(5)), Inclusive(secs(6))).unwrap();
+        assert_eq!(a.intersection(&b), Some(b));
+        assert_eq!(b.intersection(&a), Some(b));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(
+            TimeSpan::try_from(secs(0)..secs(3))
+                .unwrap()
+                .intersection(&a),
+            Some(TimeSpan::try_from(secs(0)..secs(3)).unwrap())
+        );
+    }
+
+    #[test]
+    fn split_at() {
+        let span = TimeSpan::try_from(secs(0)..=secs(4)).unwrap();
+        assert_eq!(span.split_at(secs(0)), None);
+        assert_eq!(span.split_at(secs(4)), None);
+        assert_eq!(span.split_at(secs(5)), None);
+
+        let (left, right) = span.split_at(secs(1)).unwrap();
+        assert_eq!(left, TimeSpan::try_from(secs(0)..secs(1)).unwrap());
+        assert_eq!(right, TimeSpan::try_from(secs(1)..=secs(4)).unwrap());
+        assert!(!left.overlaps(&right));
+        for millis in (0..=5000).step_by(250) {
+            let time = Duration::from_millis(millis);
+            assert_eq!(
+                span.contains(time),
+                left.contains(time) != right.contains(time),
+                "{time:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn set_min_max() {
+        let mut span = TimeSpan::try_from(secs(2)..secs(4)).unwrap();
+        span.set_min(TimeBound::Inclusive(secs(1)))
+            .unwrap()
+            .set_max(TimeBound::Inclusive(secs(5)))
+            .unwrap();
+        assert_eq!(span, TimeSpan::try_from(secs(1)..=secs(5)).unwrap());
+
+        assert!(matches!(
+            span.set_min(TimeBound::Inclusive(secs(6))),
+            Err(NewTimeSpanError::MinGreaterThanMax { .. })
+        ));
+        assert!(matches!(
+            span.set_max(TimeBound::Exclusive(secs(1)))
+                .and_then(|span| span.set_min(TimeBound::Exclusive(secs(1)))),
+            Err(NewTimeSpanError::NotTime { .. })
+        ));
+        assert_eq!(
+            span,
+            TimeSpan::new(TimeBound::Inclusive(secs(1)), TimeBound::Exclusive(secs(1))).unwrap()
+        );
+    }
+
+    #[test]
+    fn extend_shrink() {
+        let mut span = TimeSpan::try_from(secs(2)..secs(4)).unwrap();
+        span.extend_max_by(secs(1)).extend_min_by(secs(3));
+        assert_eq!(span, TimeSpan::try_from(secs(0)..secs(5)).unwrap());
+
+        span.shrink_max_by(secs(2))
+            .unwrap()
+            .shrink_min_by(secs(1))
+            .unwrap();
+        assert_eq!(span, TimeSpan::try_from(secs(1)..secs(3)).unwrap());
+
+        assert!(matches!(
+            span.shrink_max_by(secs(3)),
+            Err(NewTimeSpanError::MinGreaterThanMax { .. })
+        ));
+        assert!(matches!(
+            span.shrink_min_by(secs(3)),
+            Err(NewTimeSpanError::MinGreaterThanMax { .. })
+        ));
+        assert_eq!(span, TimeSpan::try_from(secs(1)..secs(3)).unwrap());
+    }
+
+    #[test]
+    fn progress_delta() {
+        let mut progress = TimeSpanProgress::default();
+
+        progress.update(1., 0.25);
+        assert_eq!(progress.delta(), 1.);
+        assert_eq!(progress.delta_percentage(), 0.25);
+        assert_eq!(progress.direction(), Some(TimeDirection::Forward));
+
+        progress.update(0.5, 0.125);
+        assert_eq!(progress.delta(), -0.5);
+        assert_eq!(progress.delta_percentage(), -0.125);
+        assert_eq!(progress.direction(), Some(TimeDirection::Backward));
+
+        // looped back to the start of the span
+        progress.update(4., 1.);
+        progress.update(0., 0.);
+        assert_eq!(progress.delta(), -4.);
+        assert_eq!(progress.delta_percentage(), -1.);
+    }
+
+    #[test]
+    fn progress_is_entering() {
+        let progress = |previous_percentage: f32, now_percentage: f32| TimeSpanProgress {
+            now_percentage,
+            previous_percentage,
+            ..Default::default()
+        };
+        assert!(progress(-0.5, 0.).is_entering());
+        assert!(progress(-0.5, 0.5).is_entering());
+        assert!(progress(1.5, 1.).is_entering());
+        assert!(progress(1.5, 0.5).is_entering());
+        assert!(!progress(0., 0.5).is_entering());
+        assert!(!progress(0.5, 1.5).is_entering());
+        assert!(!progress(-1., -0.5).is_entering());
+    }
+
+    #[test]
+    fn progress_map_percentages() {
+        let progress = TimeSpanProgress {
+            now_percentage: 0.5,
+            previous_percentage: 1.5,
+            ..Default::default()
+        };
+        assert_eq!(progress.map_now_percentage(|p| p * 2.), 1.);
+        assert_eq!(
+            progress.map_both_percentages(|p| p.clamp(0., 1.)),
+            (0.5, 1.)
+        );
+    }
+
+    #[test]
+    fn progress_in_range() {
+        let progress = |now_percentage: f32| TimeSpanProgress {
+            now_percentage,
+            ..Default::default()
+        };
+        for p in [0., 0.5, 1.] {
+            assert!(progress(p).in_range());
+            assert!(!progress(p).is_overshooting());
+            assert!(!progress(p).is_undershooting());
+        }
+        assert!(!progress(1.5).in_range());
+        assert!(progress(1.5).is_overshooting());
+        assert!(!progress(-0.5).in_range());
+        assert!(progress(-0.5).is_undershooting());
+        assert!(progress(f32::INFINITY).is_overshooting());
+    }
+
+    #[test]
+    fn progress_is_exiting() {
+        let progress = |previous_percentage: f32, now_percentage: f32| TimeSpanProgress {
+            now_percentage,
+            previous_percentage,
+            ..Default::default()
+        };
+        assert!(progress(0.5, 1.5).is_exiting());
+        assert!(progress(1., 1.5).is_exiting());
+        assert!(progress(0.5, -0.5).is_exiting());
+        assert!(progress(0., -0.5).is_exiting());
+        assert!(!progress(0.5, 1.).is_exiting());
+        assert!(!progress(-0.5, 0.5).is_exiting());
+        assert!(!progress(1.5, 2.).is_exiting());
+    }
+
+    #[test]
+    fn progress_is_active_first_frame() {
+        let mut world = World::default();
+        let span = world.spawn(TimeSpanProgress::default()).id();
+        let system = world.register_system(|q: Query<Ref<TimeSpanProgress>>| {
+            TimeSpanProgress::is_active_first_frame(&q.single())
+        });
+        assert!(world.run_system(system).unwrap());
+        world
+            .get_mut::<TimeSpanProgress>(span)
+            .unwrap()
+            .update(1., 0.5);
+        assert!(!world.run_system(system).unwrap());
+    }
+
+    #[test]
+    fn progress_clamped() {
+        let progress = TimeSpanProgress {
+            now_percentage: 1.5,
+            now: 3.,
+            previous_percentage: -0.5,
+            previous: -1.,
+        };
+        assert_eq!(
+            progress.clamped(),
+            TimeSpanProgress {
+                now_percentage: 1.,
+                now: 3.,
+                previous_percentage: 0.,
+                previous: -1.,
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let span = TimeSpan::try_from(secs(1)..secs(3)).unwrap();
+        let json = serde_json::to_string(&span).unwrap();
+        assert_eq!(json, r#"{"min":{"Inclusive":1.0},"max":{"Exclusive":3.0}}"#);
+        assert_eq!(serde_json::from_str::<TimeSpan>(&json).unwrap(), span);
+
+        assert!(serde_json::from_str::<TimeSpan>(
+            r#"{"min":{"Inclusive":3.0},"max":{"Exclusive":1.0}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn normalize_bounds() {
+        let span = TimeSpan::try_from(secs(2)..secs(5)).unwrap();
+        assert_eq!(span.normalize_bounds(secs(10)), (0.2, 0.5));
+        assert_eq!(span.normalize_bounds(secs(0)), (0., 0.));
+    }
+
+    #[test]
+    fn shift_by() {
+        let span = TimeSpan::try_from(secs(2)..=secs(5)).unwrap();
+        assert_eq!(
+            span.shift_by(secs(3)).unwrap(),
+            TimeSpan::try_from(secs(5)..=secs(8)).unwrap()
+        );
+        assert!(matches!(
+            span.shift_by(Duration::MAX),
+            Err(NewTimeSpanError::Overflow)
+        ));
+        assert_eq!(
+            span.shift_backward_by(secs(1)).unwrap(),
+            TimeSpan::try_from(secs(1)..=secs(4)).unwrap()
+        );
+        assert_eq!(
+            span.shift_backward_by(secs(3)).unwrap(),
+            TimeSpan::try_from(secs(0)..=secs(2)).unwrap()
+        );
+        assert!(matches!(
+            TimeSpan::try_from(secs(2)..secs(5))
+                .unwrap()
+                .shift_backward_by(secs(6)),
+            Ok(span) if span.length().is_zero()
+        ));
+    }
+
+    #[test]
+    fn from_start() {
+        assert_eq!(
+            TimeSpan::from_start(secs(5), secs(10)).unwrap(),
+            TimeSpan::try_from(secs(5)..secs(10)).unwrap()
+        );
+        assert!(TimeSpan::from_start(secs(11), secs(10)).is_err());
+    }
+
+    #[test]
+    fn from_offset() {
+        assert_eq!(
+            TimeSpan::from_offset(secs(2), secs(3)).unwrap(),
+            TimeSpan::try_from(secs(2)..secs(5)).unwrap()
+        );
+        assert!(matches!(
+            TimeSpan::from_offset(secs(2), secs(0)),
+            Err(NewTimeSpanError::NotTime { .. })
+        ));
+        assert!(matches!(
+            TimeSpan::from_offset(Duration::MAX, secs(1)),
+            Err(NewTimeSpanError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn centered_at() {
+        assert_eq!(
+            TimeSpan::centered_at(secs(5), secs(2)).unwrap(),
+            TimeSpan::try_from(secs(3)..=secs(7)).unwrap()
+        );
+        assert_eq!(
+            TimeSpan::centered_at(secs(5), secs(0)).unwrap(),
+            TimeSpan::try_from(secs(5)..=secs(5)).unwrap()
+        );
+        assert_eq!(
+            TimeSpan::centered_at(secs(1), secs(2)).unwrap(),
+            TimeSpan::try_from(secs(0)..=secs(3)).unwrap()
+        );
+        assert!(matches!(
+            TimeSpan::centered_at(Duration::MAX, secs(1)),
+            Err(NewTimeSpanError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn from_percentage_range() {
+        assert_eq!(
+            TimeSpan::from_percentage_range(0.0, 0.5, secs(10)).unwrap(),
+            TimeSpan::try_from(secs(0)..secs(5)).unwrap()
+        );
+        assert!(matches!(
+            TimeSpan::from_percentage_range(0.5, 0.5, secs(10)),
+            Err(NewTimeSpanError::NotTime { .. })
+        ));
+        assert!(matches!(
+            TimeSpan::from_percentage_range(0.5, 0.2, secs(10)),
+            Err(NewTimeSpanError::MinGreaterThanMax { .. })
+        ));
+        assert!(matches!(
+            TimeSpan::from_percentage_range(-0.1, 1.1, secs(10)),
+            Err(NewTimeSpanError::PercentageOutOfRange { .. })
+        ));
+    }
+}
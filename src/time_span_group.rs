@@ -0,0 +1,557 @@
+use std::cmp::Ordering;
+
+use bevy_ecs::{entity::EntityHashMap, prelude::*};
+use bevy_hierarchy::prelude::*;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::prelude::*;
+
+use crate::{time_runner::*, time_span::*};
+
+/// Marks a [`TimeSpan`] as a group of other [`TimeSpan`].
+///
+/// Children of this entity with [`TimeSpan`] will get their [`TimeSpanProgress`]
+/// from this group's [`TimeSpanProgress`] instead of directly from a runner.
+/// Children spans are in the same time coordinate as this group span and
+/// only ever active while this group span is active.
+/// Groups can be nested.
+///
+/// Children are always processed in ascending order of their [`TimeSpan::min`]
+/// regardless of their order in [`Children`].
+#[derive(Debug, Default, Clone, Copy, Component)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component))]
+pub struct TimeSpanGroup;
+
+type GroupSpanQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static TimeSpan,
+        Option<&'static mut TimeSpanProgress>,
+        Option<&'static Children>,
+        Has<TimeSpanGroup>,
+        Option<&'static mut TimeSpanState>,
+    ),
+    Without<SkipTimeSpan>,
+>;
+
+/// System for updating any [`TimeSpan`] under a [`TimeSpanGroup`] with the
+/// correct [`TimeSpanProgress`] and [`TimeSpanState`] by their group.
+///
+/// Sends [`TimeSpanEntered`] and [`TimeSpanExited`] for spans in the group with
+/// the runner of the outermost group.
+///
+/// The sorted order of children is cached and only sorted again when the
+/// group's [`Children`] or a child's [`TimeSpan`] changed.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+pub fn time_span_group_system(
+    mut commands: Commands,
+    q_root_group: Query<
+        (Entity, Option<&Parent>, Option<&TimeSpanRef>),
+        (With<TimeSpanGroup>, With<TimeSpan>),
+    >,
+    q_is_group: Query<(), With<TimeSpanGroup>>,
+    q_group_children: Query<(Entity, &Children), With<TimeSpanGroup>>,
+    q_changed_group: Query<Entity, (With<TimeSpanGroup>, Changed<Children>)>,
+    q_changed_span: Query<&Parent, Changed<TimeSpan>>,
+    mut q_span: GroupSpanQuery,
+    mut sorted_children: Local<EntityHashMap<Vec<Entity>>>,
+    mut entered_writer: EventWriter<TimeSpanEntered>,
+    mut exited_writer: EventWriter<TimeSpanExited>,
+) {
+    sorted_children.retain(|group, _| q_group_children.contains(*group));
+    for group in q_changed_group
+        .iter()
+        .chain(q_changed_span.iter().map(Parent::get))
+    {
+        sorted_children.remove(&group);
+    }
+    for (group, children) in &q_group_children {
+        sorted_children.entry(group).or_insert_with(|| {
+            let mut children = children.to_vec();
+            children.sort_by_cached_key(|child| {
+                q_span.get(*child).ok().map(|(span, ..)| {
+                    let min = span.min();
+                    (min.duration(), min.is_exclusive())
+                })
+            });
+            children
+        });
+    }
+
+    for (group_entity, parent, span_ref) in &q_root_group {
+        if parent.is_some_and(|parent| q_is_group.contains(parent.get())) {
+            continue;
+        }
+        let Ok((group_span, group_progress, _, _, group_state)) = q_span.get(group_entity) else {
+            continue;
+        };
+        let runner = span_ref
+            .map(|span_ref| span_ref.0)
+            .or(parent.map(Parent::get))
+            .unwrap_or(group_entity);
+        let group_span = *group_span;
+        let group_progress = group_progress.copied();
+        let group_state = group_state.copied().unwrap_or_default();
+        update_group(
+            &mut commands,
+            &mut q_span,
+            &sorted_children,
+            (&mut entered_writer, &mut exited_writer),
+            runner,
+            group_entity,
+            group_span,
+            group_progress,
+            group_state,
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update_group(
+    commands: &mut Commands,
+    q_span: &mut GroupSpanQuery,
+    sorted_children: &EntityHashMap<Vec<Entity>>,
+    writers: (
+        &mut EventWriter<TimeSpanEntered>,
+        &mut EventWriter<TimeSpanExited>,
+    ),
+    runner: Entity,
+    group_entity: Entity,
+    group_span: TimeSpan,
+    group_progress: Option<TimeSpanProgress>,
+    group_state: TimeSpanState,
+) {
+    let Some(children) = sorted_children.get(&group_entity) else {
+        return;
+    };
+
+    let group_min = TimeSpan::min(&group_span).duration().as_secs_f32();
+    for &child_entity in children {
+        let Ok((child_span, child_progress, _, is_group, child_state)) =
+            q_span.get_mut(child_entity)
+        else {
+            continue;
+        };
+        let child_span = *child_span;
+        let new_progress = group_progress
+            .and_then(|progress| child_progress_of(&group_span, &progress, &child_span));
+        // Children of an inactive group are before or after it as a whole
+        let new_state = match group_progress {
+            Some(progress) => child_span.quotient(progress.now + group_min).into(),
+            None => group_state,
+        };
+        set_span_state(commands, child_entity, child_state, new_state);
+
+        let child_progress = match (new_progress, child_progress) {
+            (Some(new_progress), Some(mut child_progress)) => {
+                child_progress.update(new_progress.now, new_progress.now_percentage);
+                Some(*child_progress)
+            }
+            (Some(new_progress), None) => {
+                commands.entity(child_entity).insert(new_progress);
+                let event = TimeSpanEntered {
+                    span: child_entity,
+                    runner,
+                };
+                commands.trigger_targets(event.clone(), child_entity);
+                writers.0.send(event);
+                Some(new_progress)
+            }
+            (None, child_progress) => {
+                if child_progress.is_some() {
+                    commands.entity(child_entity).remove::<TimeSpanProgress>();
+                    let event = TimeSpanExited {
+                        span: child_entity,
+                        runner,
+                    };
+                    commands.trigger_targets(event.clone(), child_entity);
+                    writers.1.send(event);
+                }
+                None
+            }
+        };
+
+        if is_group {
+            update_group(
+                commands,
+                q_span,
+                sorted_children,
+                (&mut *writers.0, &mut *writers.1),
+                runner,
+                child_entity,
+                child_span,
+                child_progress,
+                new_state,
+            );
+        }
+    }
+}
+
+/// Progress of `child_span` from the progress of its group.
+/// Returns `None` if the child span is not active.
+fn child_progress_of(
+    group_span: &TimeSpan,
+    group_progress: &TimeSpanProgress,
+    child_span: &TimeSpan,
+) -> Option<TimeSpanProgress> {
+    use DurationQuotient::*;
+
+    let group_min = group_span.min().duration().as_secs_f32();
+    let child_min = child_span.min().duration().as_secs_f32();
+    let child_length = child_span.length().as_secs_f32();
+
+    let now = group_progress.now - (child_min - group_min);
+    let previous = group_progress.previous - (child_min - group_min);
+
+    match (
+        child_span.quotient(group_progress.previous + group_min),
+        child_span.quotient(group_progress.now + group_min),
+    ) {
+        (Before, Before) | (After, After) => return None,
+        _ => {}
+    }
+
+    let percentage = |secs: f32| {
+        if child_length > 0. {
+            return secs / child_length;
+        }
+        match secs.total_cmp(&0.) {
+            Ordering::Greater => f32::INFINITY,
+            Ordering::Equal => match group_progress.now.total_cmp(&group_progress.previous) {
+                Ordering::Less => f32::NEG_INFINITY,
+                _ => f32::INFINITY,
+            },
+            Ordering::Less => f32::NEG_INFINITY,
+        }
+    };
+
+    Some(TimeSpanProgress {
+        now_percentage: percentage(now),
+        now,
+        previous_percentage: percentage(previous),
+        previous,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use bevy::tasks::{ComputeTaskPool, TaskPool};
+    use bevy_ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::{time_runner::test::run_systems, time_runner_system, TimeDirection, TimeRunner};
+
+    fn secs(secs: f32) -> Duration {
+        Duration::from_secs_f32(secs)
+    }
+
+    fn test_world() -> World {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::default();
+        world.init_resource::<Events<TimeSpanEntered>>();
+        world.init_resource::<Events<TimeSpanExited>>();
+        world
+    }
+
+    #[test]
+    fn children_sorted_by_min() {
+        #[derive(Resource, Default)]
+        struct Order(Vec<Entity>);
+
+        let mut world = test_world();
+        world.init_resource::<Order>();
+        world.add_observer(
+            |trigger: Trigger<OnAdd, TimeSpanProgress>, mut order: ResMut<Order>| {
+                order.0.push(trigger.entity());
+            },
+        );
+
+        let mut group = Entity::PLACEHOLDER;
+        let mut spans = [Entity::PLACEHOLDER; 3];
+        let runner = world
+            .spawn(TimeRunner::new(secs(10.)))
+            .with_children(|c| {
+                group = c
+                    .spawn((
+                        TimeSpanGroup,
+                        TimeSpan::try_from(secs(0.)..secs(10.)).unwrap(),
+                    ))
+                    .with_children(|c| {
+                        spans[2] = c
+                            .spawn(TimeSpan::try_from(secs(1.)..secs(5.)).unwrap())
+                            .id();
+                        spans[0] = c
+                            .spawn(TimeSpan::try_from(secs(0.)..=secs(5.)).unwrap())
+                            .id();
+                        spans[1] = c
+                            .spawn(
+                                TimeSpan::new(
+                                    TimeBound::Exclusive(secs(0.)),
+                                    TimeBound::Inclusive(secs(5.)),
+                                )
+                                .unwrap(),
+                            )
+                            .id();
+                    })
+                    .id();
+            })
+            .id();
+        world.get_mut::<TimeRunner>(runner).unwrap().tick(2.);
+        world.run_system_once(time_runner_system).unwrap();
+        world.run_system_once(time_span_group_system).unwrap();
+
+        assert_eq!(
+            world.resource::<Order>().0,
+            [group, spans[0], spans[1], spans[2]]
+        );
+    }
+
+    #[test]
+    fn children_sorted_again_on_span_change() {
+        let mut world = test_world();
+
+        let mut spans = [Entity::PLACEHOLDER; 2];
+        let runner = world
+            .spawn(TimeRunner::new(secs(10.)))
+            .with_children(|c| {
+                c.spawn((
+                    TimeSpanGroup,
+                    TimeSpan::try_from(secs(0.)..secs(10.)).unwrap(),
+                ))
+                .with_children(|c| {
+                    spans[0] = c
+                        .spawn(TimeSpan::try_from(secs(2.)..secs(5.)).unwrap())
+                        .id();
+                    spans[1] = c
+                        .spawn(TimeSpan::try_from(secs(3.)..secs(5.)).unwrap())
+                        .id();
+                });
+            })
+            .id();
+        let systems = [
+            world.register_system(time_runner_system),
+            world.register_system(time_span_group_system),
+        ];
+        let tick = |world: &mut World, secs: f32| {
+            world.get_mut::<TimeRunner>(runner).unwrap().tick(secs);
+            run_systems(world, &systems);
+            world
+                .resource::<Events<TimeSpanEntered>>()
+                .iter_current_update_events()
+                .map(|e| e.span)
+                .filter(|span| spans.contains(span))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(tick(&mut world, 1.), []);
+        *world.get_mut::<TimeSpan>(spans[0]).unwrap() =
+            TimeSpan::try_from(secs(4.)..secs(5.)).unwrap();
+        assert_eq!(tick(&mut world, 3.5), [spans[1], spans[0]]);
+    }
+
+    #[test]
+    fn nested_groups() {
+        let mut world = test_world();
+
+        let mut group = Entity::PLACEHOLDER;
+        let mut inner_group = Entity::PLACEHOLDER;
+        let mut spans = [Entity::PLACEHOLDER; 3];
+        let runner = world
+            .spawn(TimeRunner::new(secs(10.)))
+            .with_children(|c| {
+                group = c
+                    .spawn((
+                        TimeSpanGroup,
+                        TimeSpan::try_from(secs(2.)..secs(8.)).unwrap(),
+                    ))
+                    .with_children(|c| {
+                        spans[0] = c
+                            .spawn(TimeSpan::try_from(secs(2.)..secs(4.)).unwrap())
+                            .id();
+                        inner_group = c
+                            .spawn((
+                                TimeSpanGroup,
+                                TimeSpan::try_from(secs(4.)..secs(8.)).unwrap(),
+                            ))
+                            .with_children(|c| {
+                                spans[1] = c
+                                    .spawn(TimeSpan::try_from(secs(4.)..secs(6.)).unwrap())
+                                    .id();
+                                spans[2] = c
+                                    .spawn(TimeSpan::try_from(secs(6.)..secs(8.)).unwrap())
+                                    .id();
+                            })
+                            .id();
+                    })
+                    .id();
+            })
+            .id();
+        let systems = [
+            world.register_system(time_runner_system),
+            world.register_system(time_span_group_system),
+        ];
+        let tick = |world: &mut World, secs: f32| {
+            world.get_mut::<TimeRunner>(runner).unwrap().tick(secs);
+            run_systems(world, &systems);
+        };
+        let progress = |world: &World, entity: Entity| {
+            world
+                .get::<TimeSpanProgress>(entity)
+                .map(|progress| (progress.now, progress.now_percentage))
+        };
+
+        tick(&mut world, 1.);
+        assert_eq!(progress(&world, group), None);
+        assert_eq!(progress(&world, spans[0]), None);
+
+        tick(&mut world, 2.);
+        assert_eq!(progress(&world, group), Some((1., 1. / 6.)));
+        assert_eq!(progress(&world, spans[0]), Some((1., 0.5)));
+        assert_eq!(progress(&world, inner_group), None);
+        assert_eq!(progress(&world, spans[1]), None);
+
+        tick(&mut world, 2.);
+        assert_eq!(progress(&world, group), Some((3., 0.5)));
+        assert_eq!(progress(&world, inner_group), Some((1., 0.25)));
+        assert_eq!(progress(&world, spans[1]), Some((1., 0.5)));
+        assert_eq!(progress(&world, spans[2]), None);
+
+        // spans[0] is leaving its range and receive its last progress
+        // the same way as with the runner
+        assert_eq!(progress(&world, spans[0]), Some((3., 1.5)));
+        tick(&mut world, 2.);
+        assert_eq!(progress(&world, spans[0]), None);
+        assert_eq!(progress(&world, spans[2]), Some((1., 0.5)));
+
+        tick(&mut world, 3.);
+        tick(&mut world, 1.);
+        assert_eq!(progress(&world, group), None);
+        assert_eq!(progress(&world, inner_group), None);
+        assert_eq!(progress(&world, spans[2]), None);
+    }
+
+    #[test]
+    fn grouped_span_active_first_frame() {
+        for direction in [TimeDirection::Forward, TimeDirection::Backward] {
+            let mut world = test_world();
+
+            let mut span = Entity::PLACEHOLDER;
+            let mut runner = TimeRunner::new(secs(10.)).with_direction(direction);
+            runner.reset();
+            let runner = world
+                .spawn(runner)
+                .with_children(|c| {
+                    c.spawn((
+                        TimeSpanGroup,
+                        TimeSpan::try_from(secs(2.)..secs(8.)).unwrap(),
+                    ))
+                    .with_children(|c| {
+                        span = c
+                            .spawn(TimeSpan::try_from(secs(4.)..secs(6.)).unwrap())
+                            .id();
+                    });
+                })
+                .id();
+            let systems = [
+                world.register_system(time_runner_system),
+                world.register_system(time_span_group_system),
+            ];
+            let is_active_first_frame =
+                world.register_system(|In(span): In<Entity>, q: Query<Ref<TimeSpanProgress>>| {
+                    q.get(span)
+                        .ok()
+                        .map(|progress| TimeSpanProgress::is_active_first_frame(&progress))
+                });
+            let first_frame = |world: &mut World| {
+                world.get_mut::<TimeRunner>(runner).unwrap().tick(2.5);
+                run_systems(world, &systems);
+                world
+                    .run_system_with_input(is_active_first_frame, span)
+                    .unwrap()
+            };
+
+            assert_eq!(first_frame(&mut world), None, "{direction:?}");
+            assert_eq!(first_frame(&mut world), Some(true), "{direction:?}");
+            assert_eq!(first_frame(&mut world), Some(false), "{direction:?}");
+        }
+    }
+
+    #[test]
+    fn grouped_span_events_and_state() {
+        #[derive(Resource, Default)]
+        struct Received(Vec<(Entity, &'static str, Entity)>);
+
+        let mut world = test_world();
+        world.init_resource::<Received>();
+        let mut group = Entity::PLACEHOLDER;
+        let mut span = Entity::PLACEHOLDER;
+        let runner = world
+            .spawn(TimeRunner::new(secs(10.)))
+            .with_children(|c| {
+                group = c
+                    .spawn((
+                        TimeSpanGroup,
+                        TimeSpan::try_from(secs(2.)..secs(8.)).unwrap(),
+                    ))
+                    .with_children(|c| {
+                        span = c
+                            .spawn(TimeSpan::try_from(secs(4.)..secs(6.)).unwrap())
+                            .id();
+                    })
+                    .id();
+            })
+            .id();
+        world
+            .entity_mut(runner)
+            .observe(
+                |trigger: Trigger<TimeSpanEntered>, mut events: ResMut<Received>| {
+                    events.0.push((trigger.entity(), "entered", trigger.span));
+                },
+            )
+            .observe(
+                |trigger: Trigger<TimeSpanExited>, mut events: ResMut<Received>| {
+                    events.0.push((trigger.entity(), "exited", trigger.span));
+                },
+            );
+        let systems = [
+            world.register_system(time_runner_system),
+            world.register_system(time_span_group_system),
+        ];
+        let tick = |world: &mut World, secs: f32| {
+            world.get_mut::<TimeRunner>(runner).unwrap().tick(secs);
+            run_systems(world, &systems);
+            let events = std::mem::take(&mut world.resource_mut::<Received>().0);
+            (world.get::<TimeSpanState>(span).copied(), events)
+        };
+
+        assert_eq!(tick(&mut world, 1.), (Some(TimeSpanState::Before), vec![]));
+        assert_eq!(
+            tick(&mut world, 2.),
+            (
+                Some(TimeSpanState::Before),
+                vec![(runner, "entered", group)]
+            )
+        );
+        assert_eq!(
+            tick(&mut world, 2.),
+            (Some(TimeSpanState::Active), vec![(runner, "entered", span)])
+        );
+        assert_eq!(tick(&mut world, 2.), (Some(TimeSpanState::After), vec![]));
+        assert_eq!(
+            tick(&mut world, 2.),
+            (Some(TimeSpanState::After), vec![(runner, "exited", span)])
+        );
+        assert_eq!(
+            world
+                .resource::<Events<TimeSpanEntered>>()
+                .iter_current_update_events()
+                .map(|e| e.span)
+                .collect::<Vec<_>>(),
+            [group, span]
+        );
+    }
+}
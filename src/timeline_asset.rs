@@ -0,0 +1,174 @@
+use bevy_app::prelude::*;
+use bevy_asset::{io::Reader, Asset, AssetApp, AssetId, AssetLoader, Assets, LoadContext};
+use bevy_ecs::{prelude::*, world::Command};
+use bevy_hierarchy::prelude::*;
+use bevy_reflect::TypePath;
+
+use crate::{time_runner::*, time_span::*};
+
+/// A [`TimeRunner`] with its [`TimeSpan`]s loaded by [`TimelineAssetLoader`]
+///
+/// Every field of the runner is optional with `length` in seconds. Spans are
+/// validated the same way as [`TimeSpan::new`].
+///
+/// ```json
+#[doc = include_str!("../assets/example.timeline.json")]
+/// ```
+#[derive(Debug, Clone, PartialEq, Asset, TypePath, serde::Serialize, serde::Deserialize)]
+pub struct TimelineAsset {
+    /// Runner to spawn
+    pub runner: TimeRunner,
+    /// Spans to spawn as children of the runner
+    #[serde(default)]
+    pub spans: Vec<TimelineSpan>,
+}
+
+/// A [`TimeSpan`] entry of [`TimelineAsset`]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TimelineSpan {
+    /// Inserted as [`TimeSpanLabel`] if exists
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// The span
+    #[serde(flatten)]
+    pub span: TimeSpan,
+}
+
+impl TimelineAsset {
+    /// Spawn the runner with every span as its children
+    pub fn spawn(&self, commands: &mut Commands) -> Entity {
+        commands
+            .spawn(self.runner.clone())
+            .with_children(|c| {
+                for span in &self.spans {
+                    let mut entity = c.spawn(span.span);
+                    if let Some(label) = &span.label {
+                        entity.insert(TimeSpanLabel(label.clone()));
+                    }
+                }
+            })
+            .id()
+    }
+}
+
+/// Command for spawning the runner and spans of a loaded [`TimelineAsset`].
+/// Does nothing if the asset is not loaded.
+pub fn spawn_timeline_from_asset(timeline: impl Into<AssetId<TimelineAsset>>) -> impl Command {
+    let timeline = timeline.into();
+    move |world: &mut World| {
+        let Some(timeline) = world
+            .get_resource::<Assets<TimelineAsset>>()
+            .and_then(|assets| assets.get(timeline))
+            .cloned()
+        else {
+            return;
+        };
+        timeline.spawn(&mut world.commands());
+        world.flush();
+    }
+}
+
+/// [`AssetLoader`] for [`TimelineAsset`] from `.timeline.json` files
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TimelineAssetLoader;
+
+/// Error from [`TimelineAssetLoader`]
+#[derive(Debug)]
+pub enum TimelineAssetLoaderError {
+    /// Failed to read the file
+    Io(std::io::Error),
+    /// Failed to parse the file or a span is invalid
+    Json(serde_json::Error),
+}
+
+impl std::error::Error for TimelineAssetLoaderError {}
+impl std::fmt::Display for TimelineAssetLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimelineAssetLoaderError::Io(e) => write!(f, "Could not read timeline: {e}"),
+            TimelineAssetLoaderError::Json(e) => write!(f, "Could not parse timeline: {e}"),
+        }
+    }
+}
+
+impl AssetLoader for TimelineAssetLoader {
+    type Asset = TimelineAsset;
+    type Settings = ();
+    type Error = TimelineAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<TimelineAsset, TimelineAssetLoaderError> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(TimelineAssetLoaderError::Io)?;
+        serde_json::from_slice(&bytes).map_err(TimelineAssetLoaderError::Json)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["timeline.json"]
+    }
+}
+
+/// Registers [`TimelineAsset`] and [`TimelineAssetLoader`].
+/// Requires `AssetPlugin`.
+#[derive(Debug, Default)]
+pub struct TimelineAssetPlugin;
+
+impl Plugin for TimelineAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<TimelineAsset>()
+            .init_asset_loader::<TimelineAssetLoader>();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    const TIMELINE: &str = include_str!("../assets/example.timeline.json");
+
+    #[test]
+    fn parse_timeline() {
+        let timeline: TimelineAsset = serde_json::from_str(TIMELINE).unwrap();
+        assert_eq!(
+            timeline.runner,
+            TimeRunner::new(Duration::from_secs(3))
+                .with_repeat(Some((Repeat::times(1), RepeatStyle::PingPong)))
+        );
+        assert_eq!(timeline.spans.len(), 2);
+        assert_eq!(timeline.spans[0].label.as_deref(), Some("fade_in"));
+
+        let invalid = r#"{
+            "runner": { "length": 3.0 },
+            "spans": [{ "min": { "Inclusive": 2.0 }, "max": { "Inclusive": 1.0 } }]
+        }"#;
+        assert!(serde_json::from_str::<TimelineAsset>(invalid).is_err());
+    }
+
+    #[test]
+    fn spawn_timeline() {
+        let mut world = World::default();
+        let mut assets = Assets::<TimelineAsset>::default();
+        let handle = assets.add(serde_json::from_str::<TimelineAsset>(TIMELINE).unwrap());
+        world.insert_resource(assets);
+
+        spawn_timeline_from_asset(&handle).apply(&mut world);
+        let mut q_runner = world.query::<(&TimeRunner, &Children)>();
+        let (runner, children) = q_runner.single(&world);
+        assert_eq!(runner.length(), Duration::from_secs(3));
+        assert_eq!(children.len(), 2);
+        assert_eq!(
+            world.get::<TimeSpanLabel>(children[0]),
+            Some(&TimeSpanLabel::from("fade_in"))
+        );
+        assert_eq!(world.get::<TimeSpanLabel>(children[1]), None);
+    }
+}
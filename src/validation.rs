@@ -0,0 +1,206 @@
+use bevy_ecs::{entity::EntityHashSet, prelude::*};
+use bevy_hierarchy::prelude::*;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::prelude::*;
+
+use crate::{time_runner::*, time_span::*, time_span_group::*};
+
+/// Fired by [`time_span_validation_system`] for each problem found in the
+/// spans of a [`TimeRunner`] or [`TimeSpanGroup`]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Event)]
+pub struct TimeSpanValidationWarning {
+    /// [`TimeRunner`] or [`TimeSpanGroup`] of the spans
+    pub parent: Entity,
+    /// The earlier child span
+    pub span: Entity,
+    /// The later child span
+    pub other: Entity,
+    /// What's wrong between `span` and `other`
+    pub kind: TimeSpanValidationKind,
+}
+
+/// Kind of [`TimeSpanValidationWarning`]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeSpanValidationKind {
+    /// Both spans share some time so both will have [`TimeSpanProgress`] at once
+    Overlap,
+    /// There's some time between both spans where neither is active
+    Gap,
+}
+
+/// System for checking the child spans of every [`TimeRunner`] and
+/// [`TimeSpanGroup`] whose spans have changed, warning about overlapping spans
+/// or gaps between them.
+///
+/// Spans are compared in the order of [`TimeSpan`]'s [`Ord`], not [`Children`].
+///
+/// Only available with the `validate_spans` feature.
+#[allow(clippy::type_complexity)]
+pub fn time_span_validation_system(
+    q_parent: Query<&Children, Or<(With<TimeRunner>, With<TimeSpanGroup>)>>,
+    q_span: Query<&TimeSpan>,
+    q_changed: Query<&Parent, (With<TimeSpan>, Or<(Changed<TimeSpan>, Changed<Parent>)>)>,
+    q_changed_children: Query<
+        Entity,
+        (
+            Or<(With<TimeRunner>, With<TimeSpanGroup>)>,
+            Changed<Children>,
+        ),
+    >,
+    mut warning_writer: EventWriter<TimeSpanValidationWarning>,
+) {
+    let parents = q_changed
+        .iter()
+        .map(|parent| parent.get())
+        .chain(&q_changed_children)
+        .collect::<EntityHashSet>();
+    for parent in parents {
+        let Ok(children) = q_parent.get(parent) else {
+            continue;
+        };
+        let mut spans = children
+            .iter()
+            .filter_map(|child| q_span.get(*child).ok().map(|span| (*child, span)))
+            .collect::<Vec<_>>();
+        spans.sort_by_key(|(_, span)| *span);
+
+        // The span reaching the furthest so far, the only one that can
+        // overlap with or leave a gap before the next span.
+        let mut spans = spans.into_iter();
+        let Some(mut reach) = spans.next() else {
+            continue;
+        };
+        for (other_entity, other) in spans {
+            let (span_entity, span) = reach;
+            let kind = if span.overlaps(other) {
+                Some(TimeSpanValidationKind::Overlap)
+            } else if has_gap(span.max(), other.min()) {
+                Some(TimeSpanValidationKind::Gap)
+            } else {
+                None
+            };
+            if let Some(kind) = kind {
+                bevy_utils::tracing::warn!(
+                    ?parent,
+                    span = ?span_entity,
+                    other = ?other_entity,
+                    "time span validation: {kind:?}"
+                );
+                warning_writer.send(TimeSpanValidationWarning {
+                    parent,
+                    span: span_entity,
+                    other: other_entity,
+                    kind,
+                });
+            }
+            if (other.max().duration(), other.max().is_inclusive())
+                > (span.max().duration(), span.max().is_inclusive())
+            {
+                reach = (other_entity, other);
+            }
+        }
+    }
+}
+
+/// Returns true if no time is covered between `max` and a later `min`.
+fn has_gap(max: TimeBound, min: TimeBound) -> bool {
+    max.duration() < min.duration() || (max.is_exclusive() && min.is_exclusive())
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn time_span_validation() {
+        let mut world = World::default();
+        world.init_resource::<Events<TimeSpanValidationWarning>>();
+        let mut spans = Vec::new();
+        let mut group_spans = Vec::new();
+        let runner = world
+            .spawn(TimeRunner::new(secs(10)))
+            .with_children(|c| {
+                // out of order in `Children` but contiguous once sorted
+                for span in [secs(3)..secs(5), secs(0)..secs(2), secs(1)..secs(3)] {
+                    spans.push(c.spawn(TimeSpan::try_from(span).unwrap()).id());
+                }
+                spans.push(
+                    c.spawn((
+                        TimeSpanGroup,
+                        TimeSpan::try_from(secs(6)..secs(10)).unwrap(),
+                    ))
+                    .with_children(|c| {
+                        for span in [secs(0)..secs(1), secs(2)..secs(4)] {
+                            group_spans.push(c.spawn(TimeSpan::try_from(span).unwrap()).id());
+                        }
+                    })
+                    .id(),
+                );
+            })
+            .id();
+        let group = spans[3];
+
+        let system = world.register_system(time_span_validation_system);
+        let warnings = |world: &mut World| {
+            world.run_system(system).unwrap();
+            world
+                .resource_mut::<Events<TimeSpanValidationWarning>>()
+                .drain()
+                .collect::<Vec<_>>()
+        };
+        let mut found = warnings(&mut world);
+        found.sort_by_key(|warning| warning.parent != runner);
+        assert_eq!(
+            found,
+            [
+                TimeSpanValidationWarning {
+                    parent: runner,
+                    span: spans[1],
+                    other: spans[2],
+                    kind: TimeSpanValidationKind::Overlap,
+                },
+                TimeSpanValidationWarning {
+                    parent: runner,
+                    span: spans[0],
+                    other: group,
+                    kind: TimeSpanValidationKind::Gap,
+                },
+                TimeSpanValidationWarning {
+                    parent: group,
+                    span: group_spans[0],
+                    other: group_spans[1],
+                    kind: TimeSpanValidationKind::Gap,
+                },
+            ]
+        );
+
+        // unchanged spans are not checked again
+        assert_eq!(warnings(&mut world), []);
+
+        // a span inserted on an existing child
+        let child = world.spawn_empty().set_parent(runner).id();
+        assert_eq!(warnings(&mut world).len(), 2);
+        world
+            .entity_mut(child)
+            .insert(TimeSpan::try_from(secs(4)..secs(6)).unwrap());
+        *world.get_mut::<TimeSpan>(spans[2]).unwrap() =
+            TimeSpan::try_from(secs(2)..secs(3)).unwrap();
+        assert_eq!(
+            warnings(&mut world),
+            [TimeSpanValidationWarning {
+                parent: runner,
+                span: spans[0],
+                other: child,
+                kind: TimeSpanValidationKind::Overlap,
+            }]
+        );
+    }
+}